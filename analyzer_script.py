@@ -28,6 +28,10 @@ class PuzzleSolverAnalyzer:
         except FileNotFoundError:
             print(f"❌ Fichier non trouvé: {log_file}")
             return False
+
+        first_line = content.lstrip().split('\n', 1)[0]
+        if first_line.startswith('{'):
+            return self.parse_event_log(log_file)
             
         # Regex pour extraire les statistiques
         stats_pattern = r'\[Stats\] Total: (\d+) \| Vitesse: ([\d.]+) clés/s \| Instantané: ([\d.]+) clés/s \| Temps: (\d+):(\d+):(\d+) \| Cœurs: (\d+)'
@@ -63,6 +67,47 @@ class PuzzleSolverAnalyzer:
         print(f"✅ Trouvé {len(self.found_keys)} clés résolues")
         return True
     
+    def parse_event_log(self, log_file):
+        """Parse le journal d'événements JSON (event_log=..., schéma 1 décrit dans readme.md)"""
+        # Les fichiers tournés (.N) contiennent les événements les plus anciens
+        rotated = sorted(Path(log_file).parent.glob(Path(log_file).name + '.*'),
+                         key=lambda p: int(p.suffix[1:]) if p.suffix[1:].isdigit() else 0, reverse=True)
+        files = [p for p in rotated if p.suffix[1:].isdigit()] + [Path(log_file)]
+
+        for path in files:
+            with open(path, 'r', encoding='utf-8') as f:
+                for line in f:
+                    try:
+                        event = json.loads(line)
+                    except json.JSONDecodeError:
+                        continue
+                    if event.get('schema') != 1:
+                        continue
+
+                    if event['event'] == 'stats':
+                        elapsed = event['elapsed_secs']
+                        self.stats_data.append({
+                            'timestamp': datetime.datetime.fromisoformat(event['ts']),
+                            'run_id': event['run_id'],
+                            'total_keys': event['keys_checked'],
+                            'avg_speed': event['keys_checked'] / elapsed if elapsed > 0 else 0.0,
+                            'instant_speed': event['rate'],
+                            'elapsed_seconds': elapsed,
+                            'cores': max(len(event['workers']), 1),
+                        })
+                    elif event['event'] == 'hit':
+                        # Le journal ne contient jamais la clé privée : voir le fichier de résultats
+                        self.found_keys.append({
+                            'address': event['target'],
+                            'wif': 'voir found.jsonl',
+                            'decimal': f"{event['backend']} {event['worker']}",
+                            'timestamp': datetime.datetime.fromisoformat(event['ts']),
+                        })
+
+        print(f"✅ Trouvé {len(self.stats_data)} entrées de statistiques")
+        print(f"✅ Trouvé {len(self.found_keys)} clés résolues")
+        return True
+
     def generate_statistics(self):
         """Génère des statistiques détaillées"""
         if not self.stats_data:
@@ -249,7 +294,7 @@ class PuzzleSolverAnalyzer:
 
 def main():
     parser = argparse.ArgumentParser(description="Analyseur de Performance Bitcoin Puzzle Solver")
-    parser.add_argument('--log', default='solver.log', help='Fichier de log à analyser')
+    parser.add_argument('--log', default='solver_events.jsonl', help='Journal d\'événements JSON (ou ancien fichier de log texte)')
     parser.add_argument('--output', default='analysis', help='Préfixe des fichiers de sortie')
     parser.add_argument('--graph', action='store_true', help='Générer des graphiques')
     parser.add_argument('--report', action='store_true', help='Générer un rapport HTML')
@@ -262,7 +307,7 @@ def main():
     print("=" * 60)
     
     # Essayer de parser différents types de fichiers
-    log_files = [args.log, 'solver.log', 'output.log', 'bitcoin_solver.log']
+    log_files = [args.log, 'solver_events.jsonl', 'solver.log', 'output.log', 'bitcoin_solver.log']
     parsed = False
     
     for log_file in log_files:
@@ -1,19 +1,10 @@
-use criterion::{criterion_group, criterion_main, Criterion, BenchmarkId};
-use bitcoin::secp256k1::{Secp256k1, SecretKey};
-use bitcoin::{PrivateKey, PublicKey, Address, Network};
-use ibig::ubig;
-use std::collections::HashSet;
-
-fn generate_address_benchmark(secret_key: &SecretKey) -> Address {
-    let secp = Secp256k1::new();
-    let private_key = PrivateKey {
-        compressed: true,
-        network: Network::Bitcoin.into(),
-        inner: *secret_key,
-    };
-    let public_key = PublicKey::from_private_key(&secp, &private_key);
-    Address::p2pkh(&public_key, Network::Bitcoin)
-}
+use criterion::{criterion_group, criterion_main, Criterion, BenchmarkId, Throughput};
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin_puzzle_solver::batch::KeyDeriver;
+use bitcoin_puzzle_solver::dlp::{bsgs, kangaroo, DlpOutcome, DlpSearch};
+use bitcoin_puzzle_solver::strategy::{SearchStrategy, SmartStrategy};
+use bitcoin_puzzle_solver::{generate_address_variants, generate_key_patterns, secret_key_from_u256, secret_key_from_ubig, FastRng, PatternFamily, TargetSet, U256};
+use ibig::{ubig, UBig};
 
 fn benchmark_address_generation(c: &mut Criterion) {
     let mut group = c.benchmark_group("address_generation");
@@ -24,14 +15,11 @@ fn benchmark_address_generation(c: &mut Criterion) {
             BenchmarkId::new("generate_addresses", size),
             size,
             |b, &size| {
+                let secp = Secp256k1::new();
                 b.iter(|| {
                     for i in 1u32..=size {
-                        let key_bytes = i.to_be_bytes();
-                        let mut padded = [0u8; 32];
-                        padded[32 - key_bytes.len()..].copy_from_slice(&key_bytes);
-                        
-                        if let Ok(secret_key) = SecretKey::from_slice(&padded) {
-                            let _address = generate_address_benchmark(&secret_key);
+                        if let Some(secret_key) = secret_key_from_ubig(&UBig::from(i)) {
+                            let _variants = generate_address_variants(&secp, &secret_key);
                         }
                     }
                 });
@@ -42,32 +30,134 @@ fn benchmark_address_generation(c: &mut Criterion) {
     group.finish();
 }
 
-fn benchmark_key_patterns(c: &mut Criterion) {
-    let mut group = c.benchmark_group("key_patterns");
+fn benchmark_batch_derivation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_derivation");
+    let secp = Secp256k1::new();
+    let start = U256::try_from(&(ubig!(1) << 70)).unwrap();
     
-    let base_key = ubig!(0x20000000000000000u128);
+    // Clés consécutives : une multiplication scalaire par clé, ou une par lot avec inversion partagée
+    for size in [256usize, 1024, 4096, 16384].iter() {
+        let keys: Vec<U256> = (0..*size as u64).map(|i| start + U256::from(i)).collect();
+        group.throughput(Throughput::Elements(*size as u64));
+        group.bench_with_input(BenchmarkId::new("per_key", size), &keys, |b, keys| {
+            b.iter(|| {
+                for key in keys {
+                    if let Some(secret_key) = secret_key_from_u256(key) {
+                        let _variants = generate_address_variants(&secp, &secret_key);
+                    }
+                }
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("batched", size), &keys, |b, keys| {
+            let mut deriver = KeyDeriver::new(&secp, &U256::ONE, keys.len());
+            let mut hashes = Vec::new();
+            b.iter(|| deriver.derive(&secp, keys, &mut hashes));
+        });
+    }
     
-    group.bench_function("generate_patterns", |b| {
+    group.finish();
+}
+
+fn benchmark_key_stepping(c: &mut Criterion) {
+    let mut group = c.benchmark_group("key_stepping");
+    let count = 4096u64;
+    group.throughput(Throughput::Elements(count));
+    
+    // Parcours séquentiel d'une plage (incrément, comparaison à la borne, copie dans le lot)
+    let start = ubig!(1) << 70;
+    let end = &start + UBig::from(count - 1);
+    group.bench_function("ubig", |b| {
+        let mut batch = Vec::with_capacity(count as usize);
         b.iter(|| {
-            let mut patterns = Vec::new();
-            
-            // Pattern original
-            patterns.push(base_key.clone());
-            
-            // Patterns avec offsets
-            for offset in [1, 2, 3, 5, 8, 13, 21, 34, 55, 89] {
-                patterns.push(&base_key + offset);
-                if base_key > ubig!(offset) {
-                    patterns.push(&base_key - offset);
-                }
+            batch.clear();
+            let mut current = start.clone();
+            while current <= end {
+                batch.push(current.clone());
+                current += ubig!(1);
             }
-            
-            // Multiplication par facteurs premiers
-            for factor in [2, 3, 5, 7, 11, 13] {
-                patterns.push(&base_key * factor);
+        });
+    });
+    let (start_key, end_key) = (U256::try_from(&start).unwrap(), U256::try_from(&end).unwrap());
+    group.bench_function("u256", |b| {
+        let mut batch = Vec::with_capacity(count as usize);
+        b.iter(|| {
+            batch.clear();
+            let mut current = start_key;
+            while current <= end_key {
+                batch.push(current);
+                current += U256::ONE;
             }
-            
-            patterns
+        });
+    });
+    
+    // Conversion en clé privée secp256k1
+    let ubig_keys: Vec<UBig> = (0..count).map(|i| &start + UBig::from(i)).collect();
+    let u256_keys: Vec<U256> = (0..count).map(|i| start_key + U256::from(i)).collect();
+    group.bench_function("secret_key_ubig", |b| {
+        b.iter(|| ubig_keys.iter().filter_map(secret_key_from_ubig).count());
+    });
+    group.bench_function("secret_key_u256", |b| {
+        b.iter(|| u256_keys.iter().filter_map(secret_key_from_u256).count());
+    });
+    
+    group.finish();
+}
+
+fn benchmark_pubkey_search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pubkey_search");
+    group.sample_size(10);
+    let secp = Secp256k1::new();
+    
+    // Plage du puzzle 24 [2^23, 2^24 - 1], avec et sans symétrie de négation : couverture complète par bsgs
+    // (clé du puzzle 25, hors de la plage), recherche de la clé du puzzle 24 par les kangourous
+    let key = U256::from(0xdc2a04u64);
+    let target = secret_key_from_u256(&key).unwrap().public_key(&secp);
+    let outside = secret_key_from_u256(&U256::from(0x1fa5ee5u64)).unwrap().public_key(&secp);
+    let (start, end) = (U256::from(1u64 << 23), U256::from((1u64 << 24) - 1));
+    for symmetric in [false, true] {
+        let search = DlpSearch { threads: 1, symmetric, seed: 24, should_stop: &|| false, on_progress: &|_| {} };
+        group.bench_with_input(BenchmarkId::new("bsgs", symmetric), &symmetric, |b, _| {
+            b.iter(|| assert_eq!(bsgs(&secp, &outside, &start, &end, 256, &search), Ok(DlpOutcome::Exhausted)));
+        });
+        group.bench_with_input(BenchmarkId::new("kangaroo", symmetric), &symmetric, |b, _| {
+            b.iter(|| assert_eq!(kangaroo(&secp, &target, &start, &end, &search), DlpOutcome::Found(key)));
+        });
+    }
+    
+    group.finish();
+}
+
+fn benchmark_key_patterns(c: &mut Criterion) {
+    let mut group = c.benchmark_group("key_patterns");
+    
+    let base_key = U256::saturating_from(&ubig!(0x20000000000000000u128));
+    let range = (ubig!(0x20000000000000000u128), ubig!(0x3ffffffffffffffffu128));
+    let bounds = (U256::saturating_from(&range.0), U256::saturating_from(&range.1));
+    
+    let mut rng = FastRng::new(42);
+    
+    // Chaque famille séparément, sans filtrage de plage
+    for family in PatternFamily::ALL {
+        group.bench_with_input(BenchmarkId::new("family", family), &family, |b, family| {
+            let mut out = Vec::new();
+            b.iter(|| {
+                out.clear();
+                family.generate(&base_key, &mut rng, &mut out);
+            });
+        });
+    }
+    
+    group.bench_function("all_families_in_range", |b| {
+        b.iter(|| generate_key_patterns(&base_key, &PatternFamily::ALL, (&bounds.0, &bounds.1), &mut rng));
+    });
+    
+    // Lot complet du mode smart (patterns + déduplication)
+    group.bench_function("smart_batch", |b| {
+        let mut strategy = SmartStrategy::new(range.0.clone(), range.1.clone(), 1_000_000, PatternFamily::ALL.to_vec(), FastRng::new(42));
+        let mut batch = Vec::new();
+        b.iter(|| {
+            batch.clear();
+            strategy.next_batch(&mut batch);
         });
     });
     
@@ -77,11 +167,8 @@ fn benchmark_key_patterns(c: &mut Criterion) {
 fn benchmark_hashset_lookup(c: &mut Criterion) {
     let mut group = c.benchmark_group("hashset_lookup");
     
-    // Créer un HashSet de test
-    let mut test_addresses = HashSet::new();
-    for i in 1..=10000 {
-        test_addresses.insert(format!("1Address{}", i));
-    }
+    // Créer un ensemble de cibles de test
+    let test_addresses = TargetSet::from_addresses((1..=10000).map(|i| format!("1Address{}", i)));
     
     group.bench_function("lookup_existing", |b| {
         b.iter(|| {
@@ -101,6 +188,9 @@ fn benchmark_hashset_lookup(c: &mut Criterion) {
 criterion_group!(
     benches,
     benchmark_address_generation,
+    benchmark_batch_derivation,
+    benchmark_key_stepping,
+    benchmark_pubkey_search,
     benchmark_key_patterns,
     benchmark_hashset_lookup
 );
@@ -0,0 +1,270 @@
+// API HTTP locale : état de la recherche en JSON et commandes de pilotage
+use ibig::UBig;
+use serde_json::json;
+use std::net::SocketAddr;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::metrics::render_metrics;
+use crate::monitor::{Coverage, ProgressReport};
+use crate::notify::NotificationDispatcher;
+use crate::rates::RateTracker;
+use crate::{Config, Control, Statistics};
+
+pub struct ApiState {
+    pub config: Arc<Config>,
+    pub stats: Arc<Statistics>,
+    pub control: Arc<Control>,
+    pub notifications: Arc<NotificationDispatcher>,
+    pub range_size: UBig,
+}
+
+const JSON: &str = "application/json";
+const PROMETHEUS_TEXT: &str = "text/plain; version=0.0.4";
+
+impl ApiState {
+    fn status(&self) -> serde_json::Value {
+        let report = ProgressReport::collect(&self.stats, &self.range_size, Coverage::for_config(&self.config));
+
+        let workers: Vec<_> = self.stats.workers().iter().map(|worker| {
+            let rates = worker.rates();
+            json!({
+                "backend": worker.backend,
+                "id": worker.id,
+                "range_start": format!("0x{:x}", worker.range_start),
+                "range_end": format!("0x{:x}", worker.range_end),
+                "position": format!("0x{:x}", worker.position()),
+                "position_ratio": worker.position_ratio(),
+                "keys_checked": worker.keys_checked.load(Ordering::Relaxed),
+                "hits": worker.hits.load(Ordering::Relaxed),
+                "checkpoints_written": worker.checkpoints_written.load(Ordering::Relaxed),
+                "rates": rate_windows(&rates),
+                "peak_rate": rates.peak(),
+                "min_rate": rates.min(),
+                "idle_secs": rates.idle(Instant::now()).as_secs(),
+                "stalled": worker.is_stalled(),
+                "finished": worker.is_finished(),
+            })
+        }).collect();
+        let total_rates = &self.stats.rate_trackers()[0];
+
+        json!({
+            "state": self.control.state(),
+            "mode": self.config.mode,
+            "seed": self.config.seed,
+            "compute_mode": format!("{:?}", self.config.compute_mode).to_lowercase(),
+            "range": { "start": self.config.start, "end": self.config.end },
+            "elapsed_secs": report.elapsed_secs,
+            "keys_checked": report.keys_checked,
+            "rate": {
+                "total": report.rate,
+                "cpu": report.cpu_rate,
+                "gpu": report.gpu_rate,
+                "average": self.stats.get_average_rate(),
+                "windows": rate_windows(total_rates),
+                "peak": total_rates.peak(),
+                "min": total_rates.min(),
+            },
+            "range_size": report.range_size,
+            "coverage": report.coverage,
+            "success_probability": report.success_probability,
+            "remaining_keys": report.remaining_keys,
+            "eta_secs": report.eta_secs,
+            "eta_50_secs": report.eta_50_secs,
+            "found": report.found,
+            "checkpoint_requests": self.control.checkpoint_requests.load(Ordering::Relaxed),
+            "workers": workers,
+        })
+    }
+
+    // Les commandes exigent `Authorization: Bearer <api_token>` lorsque le jeton est configuré
+    fn authorized(&self, request: &Request) -> bool {
+        match &self.config.api_token {
+            None => true,
+            Some(token) => request.headers().iter().any(|h| {
+                h.field.equiv("Authorization") && h.value.as_str() == format!("Bearer {}", token)
+            }),
+        }
+    }
+
+    // Renvoie (code HTTP, type de contenu, corps)
+    fn handle(&self, request: &Request) -> (u16, &'static str, String) {
+        let path = request.url().split('?').next().unwrap_or("");
+        if (request.method(), path) == (&Method::Get, "/metrics") {
+            let body = render_metrics(&self.stats, &self.control, self.notifications.counters());
+            return (200, PROMETHEUS_TEXT, body);
+        }
+
+        let (status, body) = self.handle_json(request, path);
+        (status, JSON, body.to_string())
+    }
+
+    fn handle_json(&self, request: &Request, path: &str) -> (u16, serde_json::Value) {
+        match (request.method(), path) {
+            (Method::Get, "/status") => (200, self.status()),
+            (Method::Post, "/pause" | "/resume" | "/stop" | "/checkpoint") if !self.authorized(request) => {
+                (401, json!({ "error": "jeton d'API invalide" }))
+            },
+            (Method::Post, "/pause") => {
+                self.control.paused.store(true, Ordering::Relaxed);
+                println!("\n⏸️  Recherche mise en pause via l'API");
+                (200, json!({ "ok": true, "state": self.control.state() }))
+            },
+            (Method::Post, "/resume") => {
+                self.control.paused.store(false, Ordering::Relaxed);
+                println!("\n▶️  Reprise de la recherche via l'API");
+                (200, json!({ "ok": true, "state": self.control.state() }))
+            },
+            (Method::Post, "/stop") => {
+                self.control.stop.store(true, Ordering::Relaxed);
+                self.control.paused.store(false, Ordering::Relaxed);
+                println!("\n🛑 Arrêt demandé via l'API (points de contrôle enregistrés)");
+                (200, json!({ "ok": true, "state": self.control.state() }))
+            },
+            (Method::Post, "/checkpoint") => {
+                let request_id = self.control.checkpoint_requests.fetch_add(1, Ordering::Relaxed) + 1;
+                (200, json!({ "ok": true, "checkpoint_request": request_id }))
+            },
+            (_, "/status" | "/pause" | "/resume" | "/stop" | "/checkpoint") => {
+                (405, json!({ "error": "méthode non autorisée" }))
+            },
+            _ => (404, json!({ "error": "route inconnue" })),
+        }
+    }
+}
+
+// Débits par fenêtre glissante : { "10s": ..., "1m": ..., "15m": ... }
+fn rate_windows(rates: &RateTracker) -> serde_json::Value {
+    rates.windows().into_iter().map(|(name, rate)| (name.to_string(), json!(rate))).collect()
+}
+
+// Démarre le serveur dans un thread dédié et renvoie l'adresse effectivement écoutée
+pub fn start_api(listen: &str, state: ApiState) -> Result<(SocketAddr, JoinHandle<()>), String> {
+    let server = Server::http(listen).map_err(|e| format!("Impossible d'écouter sur {}: {}", listen, e))?;
+    let address = server
+        .server_addr()
+        .to_ip()
+        .ok_or_else(|| format!("Adresse d'écoute invalide: {}", listen))?;
+
+    let handle = thread::Builder::new()
+        .name("api".to_string())
+        .spawn(move || {
+            for request in server.incoming_requests() {
+                let (status, content_type, body) = state.handle(&request);
+                let content_type = Header::from_bytes("Content-Type", content_type).expect("en-tête valide");
+                let response = Response::from_string(body)
+                    .with_status_code(status)
+                    .with_header(content_type);
+                let _ = request.respond(response);
+            }
+        })
+        .map_err(|e| e.to_string())?;
+
+    Ok((address, handle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::DispatcherSettings;
+    use crate::results::Backend;
+    use std::time::Duration;
+
+    fn start(config: Config) -> (String, Arc<Control>, Arc<Statistics>) {
+        let stats = Arc::new(Statistics::new());
+        let control = Arc::new(Control::default());
+        let settings = DispatcherSettings {
+            max_retries: 0,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(10),
+            outbox_file: std::env::temp_dir().join("solver_api_test_outbox.jsonl").to_string_lossy().to_string(),
+            shutdown_grace: Duration::from_millis(10),
+        };
+        let state = ApiState {
+            config: Arc::new(config),
+            stats: stats.clone(),
+            control: control.clone(),
+            notifications: Arc::new(NotificationDispatcher::start(Vec::new(), settings)),
+            range_size: UBig::from(1000u32),
+        };
+        let (address, _) = start_api("127.0.0.1:0", state).unwrap();
+        (format!("http://{}", address), control, stats)
+    }
+
+    #[test]
+    fn status_reports_workers_and_rates() {
+        let (url, _, stats) = start(Config::default());
+        let worker = stats.register_worker(Backend::Cpu, 3, &UBig::from(100u32), &UBig::from(199u32));
+        worker.set_position(&UBig::from(150u32));
+        worker.add_keys(50);
+        stats.add_cpu_keys(50);
+
+        let status: serde_json::Value = reqwest::blocking::get(format!("{}/status", url)).unwrap().json().unwrap();
+        assert_eq!(status["state"], "running");
+        assert_eq!(status["keys_checked"], 50);
+        // Mode séquentiel : seules les 50 clés après la position du worker restent à parcourir
+        assert_eq!(status["remaining_keys"], "0x32");
+        assert!((status["coverage"].as_f64().unwrap() - 0.95).abs() < 1e-12);
+        assert_eq!(status["workers"][0]["id"], 3);
+        assert_eq!(status["workers"][0]["position"], "0x96");
+        assert_eq!(status["workers"][0]["position_ratio"], 0.5);
+        assert_eq!(status["workers"][0]["stalled"], false);
+        assert!(status["workers"][0]["rates"]["1m"].is_number());
+        assert!(status["rate"]["windows"]["15m"].is_number());
+    }
+
+    #[test]
+    fn metrics_use_prometheus_text_format() {
+        let (url, _, stats) = start(Config::default());
+        let worker = stats.register_worker(Backend::Gpu, 1, &UBig::from(0u32), &UBig::from(99u32));
+        worker.add_keys(1234);
+        stats.add_gpu_keys(1234);
+        stats.record_hit(Backend::Gpu, 1);
+
+        let response = reqwest::blocking::get(format!("{}/metrics", url)).unwrap();
+        assert!(response.headers()["content-type"].to_str().unwrap().starts_with("text/plain"));
+        let body = response.text().unwrap();
+        assert!(body.contains("# TYPE solver_keys_checked_total counter"));
+        assert!(body.contains("solver_keys_checked_total{backend=\"gpu\",worker=\"1\"} 1234"));
+        assert!(body.contains("solver_backend_keys_checked_total{backend=\"gpu\"} 1234"));
+        assert!(body.contains("solver_hits_total{backend=\"gpu\",worker=\"1\"} 1"));
+        assert!(body.contains("solver_notification_failures_total 0"));
+    }
+
+    #[test]
+    fn control_actions_update_shared_state() {
+        let (url, control, _) = start(Config::default());
+        let client = reqwest::blocking::Client::new();
+
+        client.post(format!("{}/pause", url)).send().unwrap();
+        assert!(control.paused.load(Ordering::Relaxed));
+        client.post(format!("{}/resume", url)).send().unwrap();
+        assert!(!control.paused.load(Ordering::Relaxed));
+
+        let reply: serde_json::Value = client.post(format!("{}/checkpoint", url)).send().unwrap().json().unwrap();
+        assert_eq!(reply["checkpoint_request"], 1);
+
+        client.post(format!("{}/stop", url)).send().unwrap();
+        assert_eq!(control.state(), "stopping");
+
+        assert_eq!(client.get(format!("{}/stop", url)).send().unwrap().status(), 405);
+        assert_eq!(client.get(format!("{}/unknown", url)).send().unwrap().status(), 404);
+    }
+
+    #[test]
+    fn control_actions_require_token_when_configured() {
+        let config = Config { api_token: Some("secret".to_string()), ..Config::default() };
+        let (url, control, _) = start(config);
+        let client = reqwest::blocking::Client::new();
+
+        assert_eq!(client.post(format!("{}/stop", url)).send().unwrap().status(), 401);
+        assert!(!control.stop.load(Ordering::Relaxed));
+
+        let response = client.post(format!("{}/stop", url)).bearer_auth("secret").send().unwrap();
+        assert_eq!(response.status(), 200);
+        assert!(control.stop.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,216 @@
+// Dérivation des clés par lots : chaque clé proche d'une clé d'ancrage du lot (ancre + d·pas, d <= taille
+// de la table) est obtenue par addition d'un multiple précalculé du pas, toutes les additions du lot
+// partageant une seule inversion. Les clés publiques sont ensuite sérialisées et hachées (HASH160) en bloc,
+// puis comparées aux empreintes des adresses cibles.
+use bitcoin::hashes::{hash160, Hash};
+use bitcoin::secp256k1::{All, PublicKey, Secp256k1};
+use std::time::{Duration, Instant};
+
+use crate::ec::{batch_add, AffinePoint};
+use crate::keys::secret_key_from_u256;
+use crate::u256::U256;
+
+// Empreintes HASH160 des clés publiques compressée puis non compressée
+pub type KeyHashes = [[u8; 20]; 2];
+
+// Tailles de lot essayées par la calibration (`batch_size=0`)
+pub const CALIBRATION_SIZES: [usize; 5] = [256, 1024, 4096, 16384, 65536];
+
+pub struct KeyDeriver {
+    step: U256,
+    // table[d - 1] = d·pas·G
+    table: Vec<AffinePoint>,
+    points: Vec<Option<AffinePoint>>,
+    pairs: Vec<(AffinePoint, AffinePoint)>,
+    targets: Vec<usize>,
+    sums: Vec<AffinePoint>,
+}
+
+impl KeyDeriver {
+    // Table des `size` premiers multiples de pas·G (vide si le pas n'est pas une clé valide)
+    pub fn new(secp: &Secp256k1<All>, step: &U256, size: usize) -> Self {
+        let mut table = Vec::with_capacity(size);
+        if let Some(step_key) = secret_key_from_u256(step) {
+            let step_point = PublicKey::from_secret_key(secp, &step_key);
+            let mut multiple = step_point;
+            for _ in 0..size {
+                table.push(AffinePoint::from_public_key(&multiple));
+                match multiple.combine(&step_point) {
+                    Ok(next) => multiple = next,
+                    Err(_) => break,
+                }
+            }
+        }
+        Self { step: *step, table, points: Vec::new(), pairs: Vec::new(), targets: Vec::new(), sums: Vec::new() }
+    }
+
+    pub fn batch_size(&self) -> usize {
+        self.table.len()
+    }
+
+    // Empreintes des clés du lot, dans l'ordre (None pour une clé qui n'est pas une clé privée valide)
+    pub fn derive(&mut self, secp: &Secp256k1<All>, keys: &[U256], out: &mut Vec<Option<KeyHashes>>) {
+        self.points.clear();
+        self.pairs.clear();
+        self.targets.clear();
+        self.sums.clear();
+
+        // Ancres par multiplication scalaire, autres clés par addition d'un multiple du pas.
+        // `previous` suit la progression ancre + d·pas pour éviter toute division quand le pas n'est pas 1.
+        let mut anchor: Option<(U256, AffinePoint)> = None;
+        let mut previous: Option<(U256, usize)> = None;
+        for &key in keys {
+            let d = anchor.and_then(|(anchor_key, _)| self.table_index(&anchor_key, &key, previous));
+            let offset = anchor.zip(d).and_then(|((_, anchor_point), d)| {
+                // Abscisses égales (clé = 2 × ancre) : l'addition affine ne s'applique pas
+                (self.table[d - 1].x != anchor_point.x).then_some((anchor_point, self.table[d - 1]))
+            });
+            match offset {
+                Some(pair) if key.bit_len() < 256 || secret_key_from_u256(&key).is_some() => {
+                    self.targets.push(self.points.len());
+                    self.pairs.push(pair);
+                    self.points.push(None);
+                    previous = Some((key, d.unwrap_or(0)));
+                },
+                Some(_) => self.points.push(None),
+                None => {
+                    let point = secret_key_from_u256(&key)
+                        .map(|secret_key| AffinePoint::from_public_key(&PublicKey::from_secret_key(secp, &secret_key)));
+                    anchor = point.map(|point| (key, point));
+                    previous = point.map(|_| (key, 0));
+                    self.points.push(point);
+                },
+            }
+        }
+
+        batch_add(&self.pairs, &mut self.sums);
+        for (&index, sum) in self.targets.iter().zip(&self.sums) {
+            self.points[index] = Some(*sum);
+        }
+
+        out.clear();
+        out.extend(self.points.iter().map(|point| point.as_ref().map(hash_point)));
+    }
+
+    // d si key = ancre + d·pas avec 1 <= d <= taille de la table. Pour un pas différent de 1, seule la
+    // clé qui suit la précédente (ancre + d'·pas) dans la progression est reconnue, sans division.
+    fn table_index(&self, anchor: &U256, key: &U256, previous: Option<(U256, usize)>) -> Option<usize> {
+        let d = if self.step == U256::ONE {
+            usize::try_from(key.checked_sub(anchor)?.to_u64()?).ok()?
+        } else {
+            let (previous_key, previous_d) = previous?;
+            (previous_key.checked_add(&self.step)? == *key).then_some(previous_d + 1)?
+        };
+        (1..=self.table.len()).contains(&d).then_some(d)
+    }
+}
+
+fn hash_point(point: &AffinePoint) -> KeyHashes {
+    [
+        hash160::Hash::hash(&point.serialize_compressed()).to_byte_array(),
+        hash160::Hash::hash(&point.serialize_uncompressed()).to_byte_array(),
+    ]
+}
+
+// Mesures chronométrées par taille essayée, après un passage de préchauffage non mesuré
+const CALIBRATION_REPEATS: u64 = 5;
+
+// Taille de lot donnant le meilleur débit sur ce processeur. Chaque taille est d'abord dérivée une fois sans
+// mesure (caches, tables), puis chronométrée CALIBRATION_REPEATS fois sur des clés consécutives différentes :
+// seule la meilleure mesure est retenue, une interruption du thread ne pouvant que ralentir une dérivation.
+pub fn calibrate_batch_size(secp: &Secp256k1<All>, sizes: &[usize]) -> usize {
+    let mut out = Vec::new();
+    let mut start = U256::ZERO;
+    start.set_bit(200);
+    let timings: Vec<(usize, Duration)> = sizes.iter().map(|&size| {
+        let mut deriver = KeyDeriver::new(secp, &U256::ONE, size);
+        let keys = |pass: u64| -> Vec<U256> {
+            let first = start + U256::from(pass * size as u64);
+            (0..size as u64).map(|i| first + U256::from(i)).collect()
+        };
+        deriver.derive(secp, &keys(0), &mut out);
+        let fastest = (1..=CALIBRATION_REPEATS)
+            .map(|pass| {
+                let keys = keys(pass);
+                let timer = Instant::now();
+                deriver.derive(secp, &keys, &mut out);
+                timer.elapsed()
+            })
+            .min()
+            .unwrap_or_default();
+        (size, fastest)
+    }).collect();
+    fastest_size(&timings).unwrap_or_else(|| sizes.first().copied().unwrap_or(1))
+}
+
+// Taille de meilleur débit (clés par seconde) parmi les durées mesurées ; la première en cas d'égalité
+fn fastest_size(timings: &[(usize, Duration)]) -> Option<usize> {
+    let mut best: Option<(f64, usize)> = None;
+    for &(size, elapsed) in timings {
+        let rate = size as f64 / elapsed.as_secs_f64().max(1e-9);
+        if best.is_none_or(|(best_rate, _)| rate > best_rate) {
+            best = Some((rate, size));
+        }
+    }
+    best.map(|(_, size)| size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::public_key_addresses;
+    use ibig::UBig;
+
+    fn expected(secp: &Secp256k1<All>, key: &U256) -> Option<KeyHashes> {
+        let secret_key = secret_key_from_u256(key)?;
+        let addresses = public_key_addresses(&PublicKey::from_secret_key(secp, &secret_key));
+        Some(addresses.map(|address| address.pubkey_hash().unwrap().to_byte_array()))
+    }
+
+    #[test]
+    fn batched_hashes_match_individual_derivation() {
+        let secp = Secp256k1::new();
+        let mut deriver = KeyDeriver::new(&secp, &U256::ONE, 64);
+        // Suite contiguë, saut, doublement de l'ancre (1 puis 2), clé nulle et clé hors de l'ordre de la courbe
+        let mut keys: Vec<U256> = (0x1764fu64..0x17700).map(U256::from).collect();
+        keys.extend([0x5749f, 0x574a0, 1, 2, 3, 0].map(U256::from));
+        let beyond_order = UBig::from_str_radix("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142", 16).unwrap();
+        keys.push(U256::try_from(&beyond_order).unwrap());
+
+        let mut hashes = Vec::new();
+        deriver.derive(&secp, &keys, &mut hashes);
+        assert_eq!(hashes.len(), keys.len());
+        for (key, hash) in keys.iter().zip(&hashes) {
+            assert_eq!(*hash, expected(&secp, key), "clé {:x}", key);
+        }
+    }
+
+    #[test]
+    fn strided_keys_use_multiples_of_the_step() {
+        let secp = Secp256k1::new();
+        let step = U256::from(0x10001);
+        let mut deriver = KeyDeriver::new(&secp, &step, 16);
+        let keys: Vec<U256> = (0..40u64).map(|i| U256::from(0x1234 + 0x10001 * i)).collect();
+        let mut hashes = Vec::new();
+        deriver.derive(&secp, &keys, &mut hashes);
+        for (key, hash) in keys.iter().zip(&hashes) {
+            assert_eq!(*hash, expected(&secp, key));
+        }
+    }
+
+    #[test]
+    fn calibration_picks_one_of_the_sizes() {
+        let size = calibrate_batch_size(&Secp256k1::new(), &[16, 64]);
+        assert!(size == 16 || size == 64);
+    }
+
+    #[test]
+    fn fastest_size_compares_rates_not_durations() {
+        let ms = Duration::from_millis;
+        // 4096 clés en 8 ms battent 256 clés en 1 ms ; à débit égal, la première taille est gardée
+        assert_eq!(fastest_size(&[(256, ms(1)), (4096, ms(8)), (16384, ms(80))]), Some(4096));
+        assert_eq!(fastest_size(&[(256, ms(1)), (1024, ms(4))]), Some(256));
+        assert_eq!(fastest_size(&[(1, Duration::ZERO), (4096, Duration::ZERO)]), Some(4096));
+        assert_eq!(fastest_size(&[]), None);
+    }
+}
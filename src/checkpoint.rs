@@ -0,0 +1,150 @@
+// Points de contrôle des workers CPU (checkpoint_core_<id>.txt) : état JSON de la stratégie de recherche
+use ibig::UBig;
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::Ordering;
+
+use crate::events::{self, Event, Level};
+use crate::stats::WorkerStats;
+use crate::strategy::SearchStrategy;
+
+fn checkpoint_path(dir: &str, core_id: usize) -> PathBuf {
+    Path::new(dir).join(format!("checkpoint_core_{}.txt", core_id))
+}
+
+pub fn save_checkpoint(dir: &str, strategy: &dyn SearchStrategy, worker: &WorkerStats) {
+    let checkpoint = json!({
+        "mode": strategy.mode(),
+        "range_start": format!("0x{:x}", worker.range_start),
+        "range_end": format!("0x{:x}", worker.range_end),
+        "parameters": strategy.parameters(),
+        "state": strategy.state(),
+    });
+    if fs::write(checkpoint_path(dir, worker.id), checkpoint.to_string()).is_ok() {
+        worker.checkpoints_written.fetch_add(1, Ordering::Relaxed);
+        events::emit(Level::Debug, Event::Checkpoint {
+            backend: worker.backend,
+            worker: worker.id,
+            position: format!("0x{:x}", strategy.position()),
+        });
+    }
+}
+
+// Restaure l'état de la stratégie si le point de contrôle correspond au même mode, à la même plage et aux
+// mêmes paramètres (pas du mode stride, masque du mode mask).
+// Les anciens points de contrôle (une simple clé décimale) sont repris comme position.
+pub fn load_checkpoint(dir: &str, worker: &WorkerStats, strategy: &mut dyn SearchStrategy) -> bool {
+    let Ok(contents) = fs::read_to_string(checkpoint_path(dir, worker.id)) else { return false };
+    let contents = contents.trim();
+
+    let state = if let Ok(key) = UBig::from_str(contents) {
+        json!({ "position": format!("0x{:x}", key) })
+    } else {
+        let Ok(checkpoint) = serde_json::from_str::<serde_json::Value>(contents) else {
+            eprintln!("⚠️  [Core {}] Point de contrôle illisible, ignoré.", worker.id);
+            return false;
+        };
+        let same_search = checkpoint["mode"] == json!(strategy.mode())
+            && checkpoint["range_start"] == format!("0x{:x}", worker.range_start)
+            && checkpoint["range_end"] == format!("0x{:x}", worker.range_end)
+            && strategy.accepts_parameters(&checkpoint["parameters"]);
+        if !same_search {
+            println!("[Core {}] Point de contrôle d'une autre recherche (mode, plage ou paramètres différents), ignoré.", worker.id);
+            return false;
+        }
+        checkpoint["state"].clone()
+    };
+
+    match strategy.restore(&state) {
+        Ok(()) => {
+            println!("[Core {}] Point de contrôle chargé: {}", worker.id, strategy.position());
+            true
+        },
+        Err(e) => {
+            eprintln!("⚠️  [Core {}] {}", worker.id, e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::results::Backend;
+    use crate::stats::Statistics;
+    use crate::strategy::{FastRng, KeyMask, MaskStrategy, ShuffleStrategy, StrideStrategy};
+    use crate::u256::U256;
+    use ibig::ubig;
+    use std::sync::Arc;
+
+    fn temp_dir(name: &str) -> String {
+        let dir = std::env::temp_dir().join(format!("solver_checkpoint_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir.to_str().unwrap().to_string()
+    }
+
+    fn worker(start: u32, end: u32) -> Arc<WorkerStats> {
+        Statistics::new().register_worker(Backend::Cpu, 0, &UBig::from(start), &UBig::from(end))
+    }
+
+    // Avance la stratégie d'un lot et l'enregistre
+    fn save_after_one_batch(dir: &str, worker: &WorkerStats, strategy: &mut dyn SearchStrategy) -> UBig {
+        strategy.next_batch(&mut Vec::<U256>::new());
+        save_checkpoint(dir, strategy, worker);
+        strategy.position()
+    }
+
+    #[test]
+    fn mask_checkpoint_resumes_only_with_the_same_mask() {
+        let dir = temp_dir("mask");
+        let worker = worker(0, 0xfffff);
+        let mask: KeyMask = "0x?????".parse().unwrap();
+        let position = save_after_one_batch(&dir, &worker, &mut MaskStrategy::new(mask.clone(), ubig!(0), ubig!(0xfffff)));
+
+        let mut resumed = MaskStrategy::new(mask, ubig!(0), ubig!(0xfffff));
+        assert!(load_checkpoint(&dir, &worker, &mut resumed));
+        assert_eq!(resumed.position(), position);
+
+        // Autre masque sur la même plage : l'indice enregistré désignerait d'autres clés
+        let other: KeyMask = "0x????1".parse().unwrap();
+        let mut changed = MaskStrategy::new(other, ubig!(0), ubig!(0xfffff));
+        assert!(!load_checkpoint(&dir, &worker, &mut changed));
+        assert_eq!(changed.position(), ubig!(1));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn stride_checkpoint_resumes_only_with_the_same_stride_and_offset() {
+        let dir = temp_dir("stride");
+        let worker = worker(3, 0xfffff);
+        let position = save_after_one_batch(&dir, &worker, &mut StrideStrategy::new(ubig!(3), ubig!(0xfffff), ubig!(5)));
+
+        let mut resumed = StrideStrategy::new(ubig!(3), ubig!(0xfffff), ubig!(5));
+        assert!(load_checkpoint(&dir, &worker, &mut resumed));
+        assert_eq!(resumed.position(), position);
+
+        assert!(!load_checkpoint(&dir, &worker, &mut StrideStrategy::new(ubig!(3), ubig!(0xfffff), ubig!(7))));
+        assert!(!load_checkpoint(&dir, &worker, &mut StrideStrategy::new(ubig!(3), ubig!(0xfffff), ubig!(2))));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn shuffle_checkpoint_resumes_with_its_permutation_key_and_the_same_block_size() {
+        let dir = temp_dir("shuffle");
+        let worker = worker(1, 0xfffff);
+        let mut strategy = ShuffleStrategy::new(ubig!(1), ubig!(0xfffff), 100, FastRng::new(3));
+        let position = save_after_one_batch(&dir, &worker, &mut strategy);
+
+        // Nouveau démarrage : autre clé tirée, reprise de la clé enregistrée
+        let mut resumed = ShuffleStrategy::new(ubig!(1), ubig!(0xfffff), 100, FastRng::new(4));
+        assert!(load_checkpoint(&dir, &worker, &mut resumed));
+        assert_eq!(resumed.position(), position);
+        assert_eq!(resumed.parameters(), strategy.parameters());
+
+        assert!(!load_checkpoint(&dir, &worker, &mut ShuffleStrategy::new(ubig!(1), ubig!(0xfffff), 200, FastRng::new(3))));
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
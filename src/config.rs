@@ -0,0 +1,462 @@
+// Configuration : valeurs par défaut, lecture et génération de config.txt
+use ibig::UBig;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::str::FromStr;
+
+use crate::encryption::PASSPHRASE_ENV;
+use crate::events::Level;
+use crate::gpu::GPUInfo;
+use crate::strategy::{parse_pattern_families, KeyMask, PatternFamily, SearchMode};
+
+// Énumérations pour les modes de calcul
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum ComputeMode {
+    CPU,
+    GPU,
+    Hybrid,
+}
+
+impl FromStr for ComputeMode {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cpu" => Ok(ComputeMode::CPU),
+            "gpu" => Ok(ComputeMode::GPU),
+            "hybrid" | "cpu+gpu" | "mixed" => Ok(ComputeMode::Hybrid),
+            _ => Err(format!("Mode de calcul invalide: {}. Utilisez 'cpu', 'gpu', ou 'hybrid'", s))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub start: String,
+    pub end: String,
+    pub cores: usize,
+    pub mode: SearchMode,
+    pub compute_mode: ComputeMode,
+    pub gpu_device_id: usize,
+    pub gpu_batch_size: usize,
+    pub cpu_gpu_ratio: f64,
+    pub switch_interval: u64,
+    pub stride: UBig,
+    pub stride_offset: UBig,
+    pub mask: Option<KeyMask>,
+    pub seed: Option<u64>,
+    pub subinterval_ratio: f64,
+    pub stop_on_find: bool,
+    pub puzzle_file: String,
+    pub baby_steps: bool,
+    pub giant_steps: bool,
+    pub bloom_filter: bool,
+    pub smart_jump: bool,
+    pub smart_patterns: Vec<PatternFamily>,
+    pub smart_script: Option<String>,
+    pub negation_symmetry: bool,
+    pub bsgs_table_size: usize,
+    pub batch_size: usize,
+    pub checkpoint_interval: u64,
+    pub checkpoint_dir: String,
+    pub results_file: String,
+    pub summary_file: Option<String>,
+    pub results_encryption_recipient: Option<String>,
+    pub results_encryption_passphrase: bool,
+    pub telegram_bot_token: Option<String>,
+    pub telegram_chat_id: Option<String>,
+    pub notifiers: Vec<String>,
+    pub webhook_url: Option<String>,
+    pub notify_command: Option<String>,
+    pub notify_file: String,
+    pub notify_max_retries: u32,
+    pub notify_backoff_ms: u64,
+    pub notify_outbox: String,
+    pub progress_notify_interval: u64,
+    pub heartbeat_timeout: u64,
+    pub worker_stall_timeout: u64,
+    pub event_log: Option<String>,
+    pub event_log_level: Level,
+    pub event_log_max_bytes: u64,
+    pub event_log_keep: usize,
+    pub event_log_interval: u64,
+    pub api_listen: Option<String>,
+    pub api_token: Option<String>,
+}
+
+impl Default for Config {
+    // Valeurs par défaut
+    fn default() -> Self {
+        Self {
+            start: "1".to_string(),
+            end: "1000000".to_string(),
+            cores: num_cpus::get(),
+            mode: SearchMode::Sequential,
+            compute_mode: ComputeMode::CPU,
+            gpu_device_id: 0,
+            gpu_batch_size: 50000,
+            cpu_gpu_ratio: 0.5,
+            switch_interval: 1000,
+            stride: UBig::from(1u8),
+            stride_offset: UBig::from(0u8),
+            mask: None,
+            seed: None,
+            subinterval_ratio: 0.001,
+            stop_on_find: true,
+            puzzle_file: "puzzle.txt".to_string(),
+            baby_steps: false,
+            giant_steps: false,
+            bloom_filter: true,
+            smart_jump: true,
+            smart_patterns: PatternFamily::ALL.to_vec(),
+            smart_script: None,
+            negation_symmetry: true,
+            bsgs_table_size: 1 << 20,
+            batch_size: 10000,
+            checkpoint_interval: 10000000,
+            checkpoint_dir: ".".to_string(),
+            results_file: "found.jsonl".to_string(),
+            summary_file: Some("found.txt".to_string()),
+            results_encryption_recipient: None,
+            results_encryption_passphrase: false,
+            telegram_bot_token: None,
+            telegram_chat_id: None,
+            notifiers: Vec::new(),
+            webhook_url: None,
+            notify_command: None,
+            notify_file: "notifications.jsonl".to_string(),
+            notify_max_retries: 5,
+            notify_backoff_ms: 2000,
+            notify_outbox: "notifications_outbox.jsonl".to_string(),
+            progress_notify_interval: 0,
+            heartbeat_timeout: 300,
+            worker_stall_timeout: 60,
+            event_log: None,
+            event_log_level: Level::Info,
+            event_log_max_bytes: 10 * 1024 * 1024,
+            event_log_keep: 5,
+            event_log_interval: 60,
+            api_listen: None,
+            api_token: None,
+        }
+    }
+}
+
+pub fn create_default_config(path: &str) {
+    let gpu_info = GPUInfo::detect();
+    let default_compute_mode = if gpu_info.available { "hybrid" } else { "cpu" };
+    
+    let config_content = format!("# Fichier de configuration pour le solveur de puzzle Bitcoin OPTIMISÉ v2.1
+# Modifiez les valeurs ci-dessous puis relancez le programme.
+
+# Plage de recherche (peut être en décimal ou en hexadécimal préfixé par 0x)
+start=0x20000000000000000
+end=0x3ffffffffffffffff
+
+# Nombre de coeurs CPU à utiliser (0 = détection automatique)
+cores=0
+
+# Mode de recherche : 'random', 'sequential', 'smart', 'stride', 'kangaroo', 'shuffle', 'mask', 'bsgs'
+# ('bsgs' et 'kangaroo' résolvent directement les cibles données par leur clé publique dans puzzle_file)
+mode=smart
+
+# Mode de calcul : 'cpu', 'gpu', 'hybrid' (cpu+gpu)
+compute_mode={}
+
+# Configuration GPU
+gpu_device_id=0
+gpu_batch_size=50000
+
+# Ratio CPU/GPU en mode hybride (0.5 = 50% CPU, 50% GPU)
+cpu_gpu_ratio=0.5
+
+# Après combien d'essais sauter vers un nouvel emplacement aléatoire (taille des blocs en mode 'shuffle')
+switch_interval=1000000
+
+# Pas et décalage du mode 'stride' : clés start+stride_offset, start+stride_offset+stride... (décimal ou 0x...)
+# Pour répartir une plage entre k machines : stride=k et stride_offset=0, 1, ..., k-1
+stride=1
+stride_offset=0
+
+# Masque du mode 'mask' : bits connus de la clé, en hexadécimal avec des '?' pour les chiffres inconnus
+# (ex. 0x3ff??????????a1??) ou sous la forme valeur/masque des bits connus (ex. 0x3ff00a100/0xfff00ff00)
+mask=
+
+# Graine des générateurs aléatoires (vide = tirée au hasard). La graine utilisée est affichée
+# et journalisée au démarrage : la réutiliser rejoue exactement les mêmes clés candidates.
+seed=
+
+# Modes 'random' et 'smart' : fraction de la plage de chaque coeur parcourue en entier à chaque saut
+# (ex: 0.001 pour 0.1%). Les sous-intervalles parcourus ne sont plus retirés ; 0 = saut tous les switch_interval essais
+subinterval_ratio=0.001
+
+# Arrêter le programme dès qu'une clé est trouvée ? (true ou false)
+stop_on_find=false
+
+# Fichier contenant la liste des adresses Bitcoin (ou des clés publiques) à trouver
+puzzle_file=puzzle.txt
+
+# Algorithmes avancés
+baby_steps=true
+giant_steps=true
+bloom_filter=false
+smart_jump=true
+# Familles de patterns du mode 'smart' : neighbours (base ± 1, 2, 3, 5...), multiples (base × 2, 3, 5...),
+# reversed (chiffres inversés), shuffled (chiffres mélangés). Seuls les candidats de la plage sont testés.
+smart_patterns=neighbours,multiples,reversed,shuffled
+# Script Rhai de candidats supplémentaires du mode 'smart' (fonction candidates(base, start, end), voir scripts/)
+smart_script=
+# Cibles de type clé publique (modes 'bsgs' et 'kangaroo') : la plage est recentrée sur son milieu et
+# P et -P, de même abscisse, sont traités ensemble (deux fois moins de points pour bsgs, ~1.5x moins pour kangaroo)
+negation_symmetry=true
+# Nombre de pas de bébé du mode 'bsgs' (table en mémoire, environ 32 octets par entrée)
+bsgs_table_size=1048576
+
+# Paramètres de performance
+# Clés dérivées par lot CPU (une seule inversion par lot) ; 0 = taille calibrée au démarrage
+batch_size=10000
+checkpoint_interval=10000000
+# Répertoire des points de contrôle (checkpoint_core_<id>.txt)
+checkpoint_dir=.
+
+# Fichier des résultats (JSON Lines : une ligne JSON par clé trouvée)
+results_file=found.jsonl
+
+# Résumé lisible des clés trouvées (laisser vide pour désactiver)
+summary_file=found.txt
+
+# Chiffrement des résultats (optionnel) : clé publique age (age1...) générée avec `age-keygen`
+# sur une autre machine. La clé privée n'est alors jamais stockée en clair sur cette machine.
+# Déchiffrement : bitcoin_puzzle_solver decrypt-results found.jsonl --identity cle.txt
+results_encryption_recipient=
+# À défaut, results_encryption_passphrase=true chiffre avec une phrase secrète lue dans la variable
+# RESULTS_PASSPHRASE ou saisie au démarrage. Ne jamais écrire la phrase secrète dans ce fichier.
+results_encryption_passphrase=false
+
+# Configuration Telegram (optionnel)
+# Créez un bot avec @BotFather et obtenez le token
+# Ajoutez le bot à un chat et obtenez le chat_id avec @userinfobot
+telegram_bot_token=
+telegram_chat_id=
+
+# Notificateurs actifs, séparés par des virgules : telegram, webhook, command, file
+# (par défaut : telegram si le bot est configuré)
+notifiers=
+# URL recevant un POST JSON à chaque événement (notificateur 'webhook')
+webhook_url=
+# Commande exécutée à chaque événement, JSON sur l'entrée standard (notificateur 'command')
+notify_command=
+# Fichier JSON Lines des événements, ou '-' pour la sortie standard (notificateur 'file')
+notify_file=notifications.jsonl
+
+# Livraison des notifications en arrière-plan : nombre de nouvelles tentatives, délai initial
+# (doublé à chaque échec) et fichier des notifications non livrées, rejouées au démarrage suivant
+notify_max_retries=5
+notify_backoff_ms=2000
+notify_outbox=notifications_outbox.jsonl
+
+# Rapport d'avancement envoyé aux notificateurs toutes les N secondes (0 = désactivé)
+progress_notify_interval=3600
+
+# Alerte si aucune clé n'est testée pendant N secondes (0 = désactivé).
+# Une alerte est aussi envoyée si un thread worker s'arrête sur une erreur.
+heartbeat_timeout=300
+
+# Signale un worker dont le compteur n'avance plus depuis N secondes (0 = désactivé)
+worker_stall_timeout=60
+
+# Journal d'événements JSON (une ligne par événement, vide = désactivé), ex: solver_events.jsonl
+# Niveaux : debug (inclut chaque point de contrôle), info, warn, error
+# Rotation quand le fichier dépasse event_log_max_bytes octets (event_log_keep anciens fichiers conservés)
+event_log=
+event_log_level=info
+event_log_max_bytes=10485760
+event_log_keep=5
+# Intervalle des événements 'stats' en secondes
+event_log_interval=60
+
+# API HTTP locale (optionnel), ex: 127.0.0.1:8080
+#   GET  /status                                  -> état JSON (vitesses, workers, couverture)
+#   GET  /metrics                                 -> métriques au format Prometheus
+#   POST /pause, /resume, /stop, /checkpoint      -> pilotage
+# Si api_token est défini, les commandes exigent l'en-tête 'Authorization: Bearer <api_token>'
+api_listen=
+api_token=
+
+# Informations GPU détectées automatiquement :
+# GPU disponible : {}
+# Nombre d'appareils : {}
+# CUDA disponible : {}
+# OpenCL disponible : {}
+", 
+    default_compute_mode,
+    gpu_info.available,
+    gpu_info.device_count,
+    gpu_info.cuda_available,
+    gpu_info.opencl_available
+    );
+
+    println!("Création d'un nouveau fichier de configuration par défaut...");
+    let mut file = File::create(path).expect("Impossible de créer le fichier de configuration.");
+    file.write_all(config_content.as_bytes())
+        .expect("Impossible d'écrire dans le fichier de configuration.");
+    
+    println!("Un fichier de configuration '{}' a été créé avec des valeurs par défaut.", path);
+    if gpu_info.available {
+        println!("🚀 GPU détecté ! Mode hybride recommandé pour de meilleures performances.");
+        for (i, name) in gpu_info.device_names.iter().enumerate() {
+            println!("  GPU {}: {}", i, name);
+        }
+    } else {
+        println!("⚠️  Aucun GPU détecté. Mode CPU configuré par défaut.");
+    }
+    println!("Veuillez le modifier selon vos besoins avant de relancer l'application.");
+}
+
+pub fn load_config(path: &str) -> Config {
+    let mut config = Config::default();
+    let mut notifiers_set = false;
+    
+    if let Ok(file) = File::open(path) {
+        for line in BufReader::new(file).lines().map_while(Result::ok) {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            
+            if let Some((key, value)) = line.split_once('=') {
+                let key = key.trim();
+                let value = value.trim();
+                
+                match key {
+                    "start" => config.start = value.to_string(),
+                    "end" => config.end = value.to_string(),
+                    "cores" => if let Ok(cores) = value.parse() { config.cores = cores },
+                    "mode" => match SearchMode::from_str(value) {
+                        Ok(mode) => config.mode = mode,
+                        Err(e) => {
+                            eprintln!("⚠️  {}", e);
+                            eprintln!("    Mode séquentiel utilisé par défaut.");
+                        }
+                    },
+                    "compute_mode" => {
+                        match ComputeMode::from_str(value) {
+                            Ok(mode) => config.compute_mode = mode,
+                            Err(e) => {
+                                eprintln!("⚠️  {}", e);
+                                eprintln!("    Mode CPU utilisé par défaut.");
+                            }
+                        }
+                    },
+                    "gpu_device_id" => if let Ok(id) = value.parse() { config.gpu_device_id = id },
+                    "gpu_batch_size" => if let Ok(size) = value.parse() { config.gpu_batch_size = size },
+                    "cpu_gpu_ratio" => if let Ok(ratio) = value.parse() { 
+                        config.cpu_gpu_ratio = ratio;
+                    },
+                    "switch_interval" => if let Ok(interval) = value.parse() { config.switch_interval = interval },
+                    "stride" => match parse_big_int(value) {
+                        Ok(stride) if stride > UBig::from(0u8) => config.stride = stride,
+                        _ => eprintln!("⚠️  Pas invalide: {}. Utilisez un entier strictement positif.", value),
+                    },
+                    "mask" if !value.is_empty() => match KeyMask::from_str(value) {
+                        Ok(mask) => config.mask = Some(mask),
+                        Err(e) => eprintln!("⚠️  {}", e),
+                    },
+                    "stride_offset" => match parse_big_int(value) {
+                        Ok(offset) => config.stride_offset = offset,
+                        Err(_) => eprintln!("⚠️  Décalage invalide: {}. Utilisez un entier positif ou nul.", value),
+                    },
+                    "seed" if !value.is_empty() => match parse_big_int(value).ok().and_then(|seed| u64::try_from(seed).ok()) {
+                        Some(seed) => config.seed = Some(seed),
+                        None => eprintln!("⚠️  Graine invalide: {}. Utilisez un entier de 64 bits.", value),
+                    },
+                    "subinterval_ratio" => match value.parse::<f64>() {
+                        Ok(ratio) if (0.0..=1.0).contains(&ratio) => config.subinterval_ratio = ratio,
+                        _ => eprintln!("⚠️  Ratio de sous-intervalle invalide: {}. Utilisez un nombre entre 0 et 1.", value),
+                    },
+                    "stop_on_find" => config.stop_on_find = value.eq_ignore_ascii_case("true"),
+                    "puzzle_file" => config.puzzle_file = value.to_string(),
+                    "baby_steps" => config.baby_steps = value.eq_ignore_ascii_case("true"),
+                    "giant_steps" => config.giant_steps = value.eq_ignore_ascii_case("true"),
+                    "bloom_filter" => config.bloom_filter = value.eq_ignore_ascii_case("true"),
+                    "smart_jump" => config.smart_jump = value.eq_ignore_ascii_case("true"),
+                    "smart_script" => config.smart_script = (!value.is_empty()).then(|| value.to_string()),
+                    "negation_symmetry" => config.negation_symmetry = value.eq_ignore_ascii_case("true"),
+                    "bsgs_table_size" => match value.parse::<usize>() {
+                        Ok(size) if size > 0 => config.bsgs_table_size = size,
+                        _ => eprintln!("⚠️  Taille de table bsgs invalide: {}. Utilisez un entier strictement positif.", value),
+                    },
+                    "smart_patterns" => match parse_pattern_families(value) {
+                        Ok(families) => config.smart_patterns = families,
+                        Err(e) => {
+                            eprintln!("⚠️  {}", e);
+                            eprintln!("    Toutes les familles de patterns sont utilisées par défaut.");
+                        }
+                    },
+                    "batch_size" => if let Ok(size) = value.parse() { config.batch_size = size },
+                    "checkpoint_interval" => if let Ok(interval) = value.parse() { config.checkpoint_interval = interval },
+                    "checkpoint_dir" if !value.is_empty() => config.checkpoint_dir = value.to_string(),
+                    "results_file" if !value.is_empty() => config.results_file = value.to_string(),
+                    "results_encryption_recipient" if !value.is_empty() => config.results_encryption_recipient = Some(value.to_string()),
+                    "results_encryption_passphrase" => config.results_encryption_passphrase = match value.to_lowercase().as_str() {
+                        "" | "false" => false,
+                        "true" => true,
+                        // Une phrase secrète en clair dans config.txt est refusée : elle est redemandée au démarrage
+                        _ => {
+                            eprintln!("⚠️  results_encryption_passphrase contient une phrase secrète en clair : elle est ignorée.");
+                            eprintln!("    Supprimez-la de config.txt (results_encryption_passphrase=true) et passez-la par {} ou au démarrage.", PASSPHRASE_ENV);
+                            eprintln!("    Seul results_encryption_recipient évite tout secret sur cette machine.");
+                            true
+                        }
+                    },
+                    "summary_file" => config.summary_file = if value.is_empty() { None } else { Some(value.to_string()) },
+                    "telegram_bot_token" if !value.is_empty() => config.telegram_bot_token = Some(value.to_string()),
+                    "telegram_chat_id" if !value.is_empty() => config.telegram_chat_id = Some(value.to_string()),
+                    "notifiers" => {
+                        config.notifiers = value.split(',')
+                            .map(|n| n.trim().to_lowercase())
+                            .filter(|n| !n.is_empty())
+                            .collect();
+                        notifiers_set = true;
+                    },
+                    "webhook_url" if !value.is_empty() => config.webhook_url = Some(value.to_string()),
+                    "notify_command" if !value.is_empty() => config.notify_command = Some(value.to_string()),
+                    "notify_file" if !value.is_empty() => config.notify_file = value.to_string(),
+                    "notify_max_retries" => if let Ok(retries) = value.parse() { config.notify_max_retries = retries },
+                    "notify_backoff_ms" => if let Ok(backoff) = value.parse() { config.notify_backoff_ms = backoff },
+                    "notify_outbox" if !value.is_empty() => config.notify_outbox = value.to_string(),
+                    "progress_notify_interval" => if let Ok(interval) = value.parse() { config.progress_notify_interval = interval },
+                    "heartbeat_timeout" => if let Ok(timeout) = value.parse() { config.heartbeat_timeout = timeout },
+                    "worker_stall_timeout" => if let Ok(timeout) = value.parse() { config.worker_stall_timeout = timeout },
+                    "event_log" => config.event_log = (!value.is_empty()).then(|| value.to_string()),
+                    "event_log_level" => match value.parse() {
+                        Ok(level) => config.event_log_level = level,
+                        Err(e) => eprintln!("Avertissement: {}", e),
+                    },
+                    "event_log_max_bytes" => if let Ok(bytes) = value.parse() { config.event_log_max_bytes = bytes },
+                    "event_log_keep" => if let Ok(keep) = value.parse() { config.event_log_keep = keep },
+                    "event_log_interval" => if let Ok(interval) = value.parse() { config.event_log_interval = interval },
+                    "api_listen" if !value.is_empty() => config.api_listen = Some(value.to_string()),
+                    "api_token" if !value.is_empty() => config.api_token = Some(value.to_string()),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // Sans liste explicite, Telegram reste actif dès que le bot est configuré
+    if !notifiers_set && config.telegram_bot_token.is_some() && config.telegram_chat_id.is_some() {
+        config.notifiers.push("telegram".to_string());
+    }
+    
+    config
+}
+
+pub fn parse_big_int(s: &str) -> Result<UBig, Box<dyn std::error::Error>> {
+    if let Some(hex_val) = s.strip_prefix("0x") {
+        Ok(UBig::from_str_radix(hex_val, 16)?)
+    } else {
+        Ok(UBig::from_str(s)?)
+    }
+}
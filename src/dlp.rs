@@ -0,0 +1,616 @@
+// Recherche de la clé privée d'une cible de type clé publique Q dans une plage [début, fin] : pas de bébé /
+// pas de géant (BSGS, exhaustif) ou kangourous de Pollard (méthode lambda, points distingués partagés).
+// Avec la symétrie de négation, la cible est décalée en Q' = Q - milieu·G, dont le logarithme k' est dans
+// [-h, h] ; P et -P ayant la même abscisse, un point calculé couvre deux clés : la table BSGS indexée par x
+// couvre les décalages -m..m, et les kangourous marchent sur les classes {P, -P}.
+use bitcoin::secp256k1::{All, PublicKey, Scalar, Secp256k1};
+use ibig::{ubig, IBig, UBig};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::ec::{batch_add, AffinePoint};
+use crate::keys::secret_key_from_u256;
+use crate::strategy::{worker_seed, FastRng};
+use crate::u256::U256;
+
+// Points avancés ensemble par thread (une inversion partagée par pas)
+const LANES: usize = 64;
+// Taille de l'ensemble de sauts des kangourous
+const JUMP_COUNT: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DlpOutcome {
+    Found(U256),
+    // BSGS : clé absente de la plage ; kangourous : non trouvée après un nombre de sauts très supérieur à l'attendu
+    Exhausted,
+    Stopped,
+}
+
+// Paramètres communs aux deux méthodes
+pub struct DlpSearch<'a> {
+    pub threads: usize,
+    pub symmetric: bool,
+    pub seed: u64,
+    // Interroge régulièrement la recherche : true pour l'interrompre
+    pub should_stop: &'a (dyn Fn() -> bool + Sync),
+    // Clés couvertes (BSGS) ou sauts effectués (kangourous) depuis le dernier appel
+    pub on_progress: &'a (dyn Fn(u64) + Sync),
+}
+
+// Cible décalée : Q = (base + k')·G avec base = début, ou le milieu de la plage avec la symétrie
+struct Problem<'a> {
+    secp: &'a Secp256k1<All>,
+    target: PublicKey,
+    start: UBig,
+    end: UBig,
+    base: UBig,
+    // Q' = Q - base·G (None si Q = base·G)
+    shifted: Option<PublicKey>,
+}
+
+impl<'a> Problem<'a> {
+    fn new(secp: &'a Secp256k1<All>, target: &PublicKey, start: &U256, end: &U256, symmetric: bool) -> Self {
+        let (start, end) = (UBig::from(*start), UBig::from(*end));
+        let base = if symmetric { &start + (&end - &start) / ubig!(2) } else { start.clone() };
+        let shifted = if base == ubig!(0) { Some(*target) } else { target.add_exp_tweak(secp, &scalar(&negate_mod_n(&base))).ok() };
+        Self { secp, target: *target, start, end, base, shifted }
+    }
+
+    // Clé base + décalage si elle est dans la plage et correspond à la cible
+    fn check(&self, offset: &IBig) -> Option<U256> {
+        let key = UBig::try_from(IBig::from(self.base.clone()) + offset).ok()?;
+        if key < self.start || key > self.end {
+            return None;
+        }
+        let key = U256::try_from(&key).ok()?;
+        let secret_key = secret_key_from_u256(&key)?;
+        (PublicKey::from_secret_key(self.secp, &secret_key) == self.target).then_some(key)
+    }
+
+    // Collision de deux points c1·Q' + d1·G = c2·Q' + d2·G (c = 0 pour un kangourou apprivoisé, ±1 pour un
+    // sauvage) : (c1 - c2)·k' = d2 - d1 modulo n
+    fn solve(&self, (c1, d1): (i8, U256), (c2, d2): (i8, U256)) -> Option<U256> {
+        let n = UBig::from(U256::CURVE_ORDER);
+        let difference = (UBig::from(d2) + &n - UBig::from(d1)) % &n;
+        let quotient = match c1 - c2 {
+            1 => difference,
+            -1 => negate_mod_n(&difference),
+            2 => difference * ((&n + ubig!(1)) / ubig!(2)) % &n,
+            -2 => negate_mod_n(&(difference * ((&n + ubig!(1)) / ubig!(2)) % &n)),
+            _ => return None,
+        };
+        // Représentant signé de k' modulo n
+        let offset = if quotient > &n / ubig!(2) { IBig::from(quotient) - IBig::from(n) } else { IBig::from(quotient) };
+        self.check(&offset)
+    }
+}
+
+fn negate_mod_n(value: &UBig) -> UBig {
+    let n = UBig::from(U256::CURVE_ORDER);
+    (&n - value % &n) % &n
+}
+
+fn signed_mod_n(value: &IBig) -> UBig {
+    let n = IBig::from(UBig::from(U256::CURVE_ORDER));
+    UBig::try_from(((value % &n) + &n) % &n).expect("reste positif")
+}
+
+// Scalaire d'une valeur déjà réduite modulo n
+fn scalar(value: &UBig) -> Scalar {
+    Scalar::from_be_bytes(U256::try_from(value).expect("valeur réduite modulo n").to_be_bytes()).expect("valeur réduite modulo n")
+}
+
+// Point value·G (None pour 0 modulo n)
+fn base_point(secp: &Secp256k1<All>, value: &UBig) -> Option<AffinePoint> {
+    let key = secret_key_from_u256(&U256::try_from(value).ok()?)?;
+    Some(AffinePoint::from_public_key(&PublicKey::from_secret_key(secp, &key)))
+}
+
+fn add_mod_n(a: U256, b: U256) -> U256 {
+    let (sum, carry) = a.overflowing_add(&b);
+    if carry || sum >= U256::CURVE_ORDER { sum.overflowing_sub(&U256::CURVE_ORDER).0 } else { sum }
+}
+
+fn neg_mod_n(value: U256) -> U256 {
+    if value == U256::ZERO { value } else { U256::CURVE_ORDER - value }
+}
+
+fn to_public_key(point: &AffinePoint) -> PublicKey {
+    PublicKey::from_slice(&point.serialize_uncompressed()).expect("point de la courbe")
+}
+
+// Sommes de couples de points : inversion partagée, sauf pour les couples de même abscisse
+// (doublement, ou None pour le point à l'infini) confiés à libsecp256k1
+#[derive(Default)]
+struct PointAdder {
+    regular: Vec<(AffinePoint, AffinePoint)>,
+    indices: Vec<usize>,
+    sums: Vec<AffinePoint>,
+}
+
+impl PointAdder {
+    fn add(&mut self, pairs: &[(AffinePoint, AffinePoint)], out: &mut Vec<Option<AffinePoint>>) {
+        self.regular.clear();
+        self.indices.clear();
+        self.sums.clear();
+        out.clear();
+        for (i, (a, b)) in pairs.iter().enumerate() {
+            if a.x == b.x {
+                let doubled = (a.y == b.y).then(|| to_public_key(a).combine(&to_public_key(a)).ok()).flatten();
+                out.push(doubled.map(|point| AffinePoint::from_public_key(&point)));
+            } else {
+                self.indices.push(i);
+                self.regular.push((*a, *b));
+                out.push(None);
+            }
+        }
+        batch_add(&self.regular, &mut self.sums);
+        for (&i, sum) in self.indices.iter().zip(&self.sums) {
+            out[i] = Some(*sum);
+        }
+    }
+}
+
+// État partagé par les threads d'une recherche
+#[derive(Default)]
+struct Shared {
+    done: AtomicBool,
+    stopped: AtomicBool,
+    found: Mutex<Option<U256>>,
+}
+
+impl Shared {
+    fn finish(&self, key: U256) {
+        *self.found.lock().unwrap() = Some(key);
+        self.done.store(true, Ordering::Relaxed);
+    }
+
+    // Fin de la recherche (clé trouvée, arrêt demandé ou autre thread terminé)
+    fn should_exit(&self, search: &DlpSearch) -> bool {
+        if !self.done.load(Ordering::Relaxed) && (search.should_stop)() {
+            self.stopped.store(true, Ordering::Relaxed);
+            self.done.store(true, Ordering::Relaxed);
+        }
+        self.done.load(Ordering::Relaxed)
+    }
+
+    fn outcome(self) -> DlpOutcome {
+        match self.found.into_inner().unwrap() {
+            Some(key) => DlpOutcome::Found(key),
+            None if self.stopped.load(Ordering::Relaxed) => DlpOutcome::Stopped,
+            None => DlpOutcome::Exhausted,
+        }
+    }
+}
+
+// Table des pas de bébé : 64 bits de poids faible de l'abscisse de j·G -> j, pour 1 <= j <= m
+fn baby_steps(secp: &Secp256k1<All>, m: u64) -> HashMap<u64, u64> {
+    let mut table = HashMap::with_capacity(m as usize);
+    let generator = base_point(secp, &ubig!(1)).expect("générateur");
+    // Chaînes parallèles j0, j0 + 1, ..., dernier pour partager les inversions : environ sqrt(m) chaînes
+    // (une multiplication scalaire chacune) de sqrt(m) additions
+    let count = ((m as f64).sqrt() as u64).clamp(1, 1024);
+    let length = m.div_ceil(count);
+    let mut chains: Vec<(u64, u64, AffinePoint)> = (0..count)
+        .map(|c| 1 + c * length)
+        .filter(|&j| j <= m)
+        .map(|j| (j, (j + length - 1).min(m), base_point(secp, &UBig::from(j)).expect("j < n")))
+        .collect();
+    let (mut adder, mut pairs, mut sums) = (PointAdder::default(), Vec::new(), Vec::new());
+    while !chains.is_empty() {
+        for (j, _, point) in &chains {
+            table.entry(point.x.low_u64()).or_insert(*j);
+        }
+        chains.retain(|(j, last, _)| j < last);
+        pairs.clear();
+        pairs.extend(chains.iter().map(|(_, _, point)| (*point, generator)));
+        adder.add(&pairs, &mut sums);
+        for (chain, sum) in chains.iter_mut().zip(&sums) {
+            chain.0 += 1;
+            chain.2 = sum.expect("(j + 1)·G n'est pas à l'infini");
+        }
+    }
+    table
+}
+
+// Chaîne de pas de géant : fenêtres de centres direction·(premier + L·r)·pas, r = 0..nombre
+struct GiantChain {
+    point: AffinePoint,
+    first: u64,
+    direction: i8,
+    steps: u64,
+    count: u64,
+}
+
+// BSGS exhaustif avec une table de `table_size` pas de bébé au plus : sans symétrie, fenêtres [c, c + m]
+// (pas m + 1) depuis le début de la plage ; avec, fenêtres [c - m, c + m] (pas 2m + 1) de part et d'autre
+// du milieu, soit deux fois moins de pas de géant pour la même table.
+pub fn bsgs(secp: &Secp256k1<All>, target: &PublicKey, start: &U256, end: &U256, table_size: usize, search: &DlpSearch) -> Result<DlpOutcome, String> {
+    let problem = Problem::new(secp, target, start, end, search.symmetric);
+    let Some(shifted) = problem.shifted else {
+        return Ok(problem.check(&IBig::from(0u8)).map_or(DlpOutcome::Exhausted, DlpOutcome::Found));
+    };
+    let (low, high) = (&problem.base - &problem.start, &problem.end - &problem.base);
+    let m = UBig::from(table_size.max(1)).min(high.clone().max(ubig!(1)));
+    let stride = if search.symmetric { ubig!(2) * &m + ubig!(1) } else { &m + ubig!(1) };
+    // Nombre de fenêtres au-dessus (centre >= 0) et au-dessous (centre < 0) de la base
+    let (above, below) = if search.symmetric {
+        ((&high + &m) / &stride + ubig!(1), (&low + &m) / &stride)
+    } else {
+        (&high / &stride + ubig!(1), ubig!(0))
+    };
+    let (Ok(above), Ok(below)) = (u64::try_from(&above), u64::try_from(&below)) else {
+        return Err("Plage trop grande pour le mode bsgs avec cette taille de table (bsgs_table_size).".to_string());
+    };
+    let m = u64::try_from(&m).expect("m <= table_size");
+    let window = u64::try_from(&stride).unwrap_or(u64::MAX);
+    let table = baby_steps(secp, m);
+
+    let threads = search.threads.max(1);
+    let lanes = (threads * LANES) as u64;
+    let shared = Shared::default();
+    // Déplacement de toutes les chaînes d'une direction à chaque pas : -direction·L·pas·G
+    let delta = |direction: i8| base_point(secp, &signed_mod_n(&(IBig::from(-direction) * IBig::from(lanes) * IBig::from(stride.clone()))));
+    let deltas = [delta(1), delta(-1)];
+    let center = |chain: &GiantChain| IBig::from(chain.direction) * IBig::from(chain.first + lanes * chain.steps) * IBig::from(stride.clone());
+
+    thread::scope(|scope| {
+        for t in 0..threads as u64 {
+            let (problem, table, shared, deltas, center, stride) = (&problem, &table, &shared, &deltas, &center, &stride);
+            scope.spawn(move || {
+                // Chaînes du thread : fenêtres au-dessus de la base, puis au-dessous
+                let mut chains = Vec::new();
+                for lane in t * LANES as u64..(t + 1) * LANES as u64 {
+                    for (direction, first, total) in [(1i8, lane, above), (-1, lane + 1, below + 1)] {
+                        if first >= total {
+                            continue;
+                        }
+                        let count = (total - first - 1) / lanes + 1;
+                        let offset = IBig::from(direction) * IBig::from(first) * IBig::from(stride.clone());
+                        match shifted.add_exp_tweak(problem.secp, &scalar(&signed_mod_n(&-&offset))) {
+                            Ok(point) => chains.push(GiantChain { point: AffinePoint::from_public_key(&point), first, direction, steps: 0, count }),
+                            Err(_) => {
+                                if let Some(key) = problem.check(&offset) {
+                                    shared.finish(key);
+                                }
+                            },
+                        }
+                    }
+                }
+
+                let (mut adder, mut pairs, mut sums) = (PointAdder::default(), Vec::new(), Vec::new());
+                while !chains.is_empty() && !shared.should_exit(search) {
+                    // Fenêtre de chaque chaîne : x(R) = x(j·G) pour R = (k' - c)·G, soit k' = c + j (ou c - j)
+                    for chain in &chains {
+                        if let Some(&j) = table.get(&chain.point.x.low_u64()) {
+                            let c = center(chain);
+                            let found = problem.check(&(&c + IBig::from(j)))
+                                .or_else(|| search.symmetric.then(|| problem.check(&(&c - IBig::from(j)))).flatten());
+                            if let Some(key) = found {
+                                shared.finish(key);
+                            }
+                        }
+                    }
+                    (search.on_progress)(window.saturating_mul(chains.len() as u64));
+
+                    chains.retain_mut(|chain| {
+                        chain.steps += 1;
+                        chain.steps < chain.count
+                    });
+                    pairs.clear();
+                    for chain in &chains {
+                        let delta = deltas[(chain.direction < 0) as usize].expect("pas non nul modulo n");
+                        pairs.push((chain.point, delta));
+                    }
+                    adder.add(&pairs, &mut sums);
+                    for (chain, sum) in chains.iter_mut().zip(&sums) {
+                        match sum {
+                            Some(point) => chain.point = *point,
+                            // Point à l'infini : k' est exactement le nouveau centre
+                            None => {
+                                if let Some(key) = problem.check(&center(chain)) {
+                                    shared.finish(key);
+                                }
+                                chain.count = chain.steps;
+                            },
+                        }
+                    }
+                    chains.retain(|chain| chain.steps < chain.count);
+                }
+            });
+        }
+    });
+    Ok(shared.outcome())
+}
+
+// Kangourou : point c·Q' + d·G (c = 0 apprivoisé, ±1 sauvage), distance d modulo n
+#[derive(Clone, Copy)]
+struct Kangaroo {
+    point: AffinePoint,
+    sign: i8,
+    distance: U256,
+    wild: bool,
+    // État précédent, pour détecter les cycles de longueur 2 de la marche sur les classes {P, -P}
+    previous: Option<(AffinePoint, i8, U256)>,
+    escape: bool,
+    since_distinguished: u64,
+}
+
+// Paramètres de la marche partagés par les threads
+struct Walk {
+    symmetric: bool,
+    jumps: Vec<U256>,
+    jump_points: Vec<AffinePoint>,
+    width: UBig,
+    distinguished_mask: u64,
+    max_since_distinguished: u64,
+}
+
+impl Walk {
+    fn jump_index(&self, kangaroo: &Kangaroo) -> usize {
+        ((kangaroo.point.x.low_u64() >> 32) as usize + kangaroo.escape as usize) % JUMP_COUNT
+    }
+}
+
+// Kangourous de Pollard (parallélisme de van Oorschot-Wiener) : la moitié des kangourous partent de points
+// connus d·G, l'autre moitié de Q' + d·G ; une collision sur un point distingué donne k'. Avec la symétrie,
+// chaque point est remplacé par celui de sa classe {P, -P} d'ordonnée paire et chaque marche repart d'un point
+// aléatoire après son point distingué (Gaudry-Schost) : environ 1,5 fois moins de sauts, mais des redémarrages
+// qui ne sont amortis que sur les grandes plages. La recherche abandonne (`Exhausted`) après environ 16 fois
+// le nombre de sauts attendu.
+pub fn kangaroo(secp: &Secp256k1<All>, target: &PublicKey, start: &U256, end: &U256, search: &DlpSearch) -> DlpOutcome {
+    let problem = Problem::new(secp, target, start, end, search.symmetric);
+    let Some(shifted) = problem.shifted else {
+        return problem.check(&IBig::from(0u8)).map_or(DlpOutcome::Exhausted, DlpOutcome::Found);
+    };
+    // Largeur parcourue : |k'| <= h avec la symétrie, k' dans [0, fin - début] sinon
+    let width = (&problem.end - &problem.base).max(ubig!(1));
+    let threads = search.threads.max(1);
+    let herd = (threads * LANES) as u64;
+    let half_bits = width.bit_len().div_ceil(2);
+
+    // Points distingués assez rares pour limiter la table, assez fréquents pour détecter rapidement une
+    // collision. Saut moyen ~ (nombre de kangourous)·sqrt(largeur)/4 pour des kangourous qui se poursuivent ;
+    // avec la symétrie, petits sauts pour que chaque marche reste près de son point de départ
+    let distinguished_bits = half_bits.saturating_sub(herd.ilog2() as usize + 2).min(32);
+    let mean = if search.symmetric {
+        (&width >> (distinguished_bits + 2)).max(ubig!(1))
+    } else {
+        ((UBig::from(herd) << half_bits) >> 2).max(ubig!(1))
+    };
+    let mut rng = FastRng::new(search.seed);
+    let jumps: Vec<U256> = (0..JUMP_COUNT)
+        .map(|_| U256::try_from(&(rng.gen_below(&(ubig!(2) * &mean)) + ubig!(1))).expect("saut < n"))
+        .collect();
+    let jump_points = jumps.iter().map(|jump| base_point(secp, &UBig::from(*jump)).expect("saut < n")).collect();
+    let walk = Walk {
+        symmetric: search.symmetric,
+        jumps,
+        jump_points,
+        width,
+        distinguished_mask: (1u64 << distinguished_bits) - 1,
+        max_since_distinguished: 8 << distinguished_bits,
+    };
+    let expected = 2f64.powi(half_bits as i32 + 1) + herd as f64 * 2f64.powi(distinguished_bits as i32);
+    let max_jumps = (16.0 * expected) as u64;
+
+    let shared = Shared::default();
+    let distinguished: Mutex<HashMap<[u8; 32], (i8, U256)>> = Mutex::new(HashMap::new());
+    let total_jumps = AtomicU64::new(0);
+    thread::scope(|scope| {
+        for t in 0..threads as u64 {
+            let (problem, walk, shared, distinguished, total_jumps) = (&problem, &walk, &shared, &distinguished, &total_jumps);
+            scope.spawn(move || {
+                let mut rng = FastRng::new(worker_seed(search.seed, t));
+                let mut herd: Vec<Kangaroo> = Vec::with_capacity(LANES);
+                for i in 0..LANES {
+                    match spawn_kangaroo(problem, &shifted, walk, i % 2 == 1, &mut rng) {
+                        Ok(kangaroo) => herd.push(kangaroo),
+                        Err(key) => shared.finish(key),
+                    }
+                }
+
+                let (mut adder, mut pairs, mut sums, mut indices) = (PointAdder::default(), Vec::new(), Vec::new(), Vec::new());
+                while !shared.should_exit(search) {
+                    if total_jumps.fetch_add(LANES as u64, Ordering::Relaxed) > max_jumps {
+                        shared.done.store(true, Ordering::Relaxed);
+                        break;
+                    }
+                    pairs.clear();
+                    indices.clear();
+                    for kangaroo in &herd {
+                        let j = walk.jump_index(kangaroo);
+                        indices.push(j);
+                        pairs.push((kangaroo.point, walk.jump_points[j]));
+                    }
+                    adder.add(&pairs, &mut sums);
+
+                    for i in 0..herd.len() {
+                        let kangaroo = herd[i];
+                        let distance = add_mod_n(kangaroo.distance, walk.jumps[indices[i]]);
+                        let Some(mut point) = sums[i] else {
+                            // Point à l'infini : c·Q' + d·G = 0, comme une collision avec l'apprivoisé 0·G
+                            match problem.solve((kangaroo.sign, distance), (0, U256::ZERO)) {
+                                Some(key) => shared.finish(key),
+                                None => respawn(&mut herd[i], problem, &shifted, walk, &mut rng, shared),
+                            }
+                            continue;
+                        };
+                        let (mut sign, mut distance) = (kangaroo.sign, distance);
+                        if search.symmetric && point.y.is_odd() {
+                            point = point.negate();
+                            sign = -sign;
+                            distance = neg_mod_n(distance);
+                        }
+                        let next = &mut herd[i];
+                        next.escape = false;
+                        match kangaroo.previous {
+                            // Cycle P -> P2 -> P : on repart du point d'abscisse minimale avec un autre saut,
+                            // choix qui ne dépend que du cycle (deux kangourous piégés fusionnent toujours)
+                            Some((previous, ..)) if search.symmetric && previous.x == point.x => {
+                                if kangaroo.point.x.to_be_bytes() < point.x.to_be_bytes() {
+                                    next.previous = Some((point, sign, distance));
+                                } else {
+                                    next.previous = Some((kangaroo.point, kangaroo.sign, kangaroo.distance));
+                                    (next.point, next.sign, next.distance) = (point, sign, distance);
+                                }
+                                next.escape = true;
+                            },
+                            _ => {
+                                next.previous = Some((kangaroo.point, kangaroo.sign, kangaroo.distance));
+                                (next.point, next.sign, next.distance) = (point, sign, distance);
+                            },
+                        }
+
+                        if next.point.x.low_u64() & walk.distinguished_mask == 0 {
+                            next.since_distinguished = 0;
+                            let entry = (next.sign, next.distance);
+                            let other = *distinguished.lock().unwrap().entry(next.point.x.to_be_bytes()).or_insert(entry);
+                            if other != entry {
+                                match problem.solve(entry, other) {
+                                    Some(key) => shared.finish(key),
+                                    // Deux kangourous de même nature sur la même trace : l'un repart ailleurs
+                                    None => respawn(next, problem, &shifted, walk, &mut rng, shared),
+                                }
+                            } else if walk.symmetric {
+                                // Avec la symétrie, chaque marche s'arrête à son premier point distingué
+                                respawn(next, problem, &shifted, walk, &mut rng, shared);
+                            }
+                        } else {
+                            next.since_distinguished += 1;
+                            // Pris dans un cycle plus long : on repart d'un nouveau point
+                            if next.since_distinguished > walk.max_since_distinguished {
+                                respawn(next, problem, &shifted, walk, &mut rng, shared);
+                            }
+                        }
+                    }
+                    (search.on_progress)(LANES as u64);
+                }
+            });
+        }
+    });
+    shared.outcome()
+}
+
+// Nouveau kangourou. Sans symétrie, apprivoisés vers le milieu (d dans largeur/2 + [0, largeur/8]) et
+// sauvages juste après k' (d dans [0, largeur/8]), comme chez van Oorschot et Wiener ; avec la symétrie,
+// apprivoisés dans [1, largeur] et sauvages dans k' + [-largeur/4, largeur/4].
+// Err(clé) si le départ sauvage est à l'infini et donne directement la clé.
+fn spawn_kangaroo(problem: &Problem, shifted: &PublicKey, walk: &Walk, wild: bool, rng: &mut FastRng) -> Result<Kangaroo, U256> {
+    let width = &walk.width;
+    let offset = match (wild, walk.symmetric) {
+        (false, false) => IBig::from(width / ubig!(2) + rng.gen_below(&(width / ubig!(8) + ubig!(1)))),
+        (false, true) => IBig::from(rng.gen_below(width) + ubig!(1)),
+        (true, false) => IBig::from(rng.gen_below(&(width / ubig!(8) + ubig!(1)))),
+        (true, true) => IBig::from(rng.gen_below(&(width / ubig!(2) + ubig!(1)))) - IBig::from(width / ubig!(4)),
+    };
+    let distance = signed_mod_n(&offset);
+    // Q' + d·G par multiplication à base fixe puis une addition (plus rapide qu'une multiplication de Q')
+    let point = match (wild, base_point(problem.secp, &distance)) {
+        (false, point) => point.expect("d > 0"),
+        (true, None) => AffinePoint::from_public_key(shifted),
+        (true, Some(point)) => match shifted.combine(&to_public_key(&point)) {
+            Ok(point) => AffinePoint::from_public_key(&point),
+            Err(_) => match problem.check(&-offset) {
+                Some(key) => return Err(key),
+                None => return spawn_kangaroo(problem, shifted, walk, wild, rng),
+            },
+        },
+    };
+    let distance = U256::try_from(&distance).expect("d < n");
+    let mut kangaroo = Kangaroo { point, sign: wild as i8, distance, wild, previous: None, escape: false, since_distinguished: 0 };
+    if walk.symmetric && point.y.is_odd() {
+        kangaroo.point = point.negate();
+        kangaroo.sign = -kangaroo.sign;
+        kangaroo.distance = neg_mod_n(distance);
+    }
+    Ok(kangaroo)
+}
+
+fn respawn(kangaroo: &mut Kangaroo, problem: &Problem, shifted: &PublicKey, walk: &Walk, rng: &mut FastRng, shared: &Shared) {
+    match spawn_kangaroo(problem, shifted, walk, kangaroo.wild, rng) {
+        Ok(fresh) => *kangaroo = fresh,
+        Err(key) => shared.finish(key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn public_key(secp: &Secp256k1<All>, key: u64) -> PublicKey {
+        PublicKey::from_secret_key(secp, &secret_key_from_u256(&U256::from(key)).unwrap())
+    }
+
+    fn search(symmetric: bool) -> DlpSearch<'static> {
+        DlpSearch { threads: 2, symmetric, seed: 5, should_stop: &|| false, on_progress: &|_| {} }
+    }
+
+    #[test]
+    fn bsgs_finds_keys_at_both_ends_and_inside_the_range() {
+        let secp = Secp256k1::new();
+        let (start, end) = (U256::from(0x8000), U256::from(0xffff));
+        for symmetric in [false, true] {
+            for key in [0x8000, 0x8001, 0xbfff, 0xc000, 0xd2a1, 0xfffe, 0xffff] {
+                let outcome = bsgs(&secp, &public_key(&secp, key), &start, &end, 100, &search(symmetric)).unwrap();
+                assert_eq!(outcome, DlpOutcome::Found(U256::from(key)), "clé {:x}, symétrie {}", key, symmetric);
+            }
+            let outside = bsgs(&secp, &public_key(&secp, 0x10000), &start, &end, 100, &search(symmetric)).unwrap();
+            assert_eq!(outside, DlpOutcome::Exhausted);
+        }
+    }
+
+    #[test]
+    fn symmetric_bsgs_covers_the_range_with_half_the_giant_steps() {
+        let secp = Secp256k1::new();
+        let covered = |symmetric: bool| {
+            let total = AtomicU64::new(0);
+            let on_progress = |count: u64| {
+                total.fetch_add(count, Ordering::Relaxed);
+            };
+            let search = DlpSearch { threads: 1, symmetric, seed: 1, should_stop: &|| false, on_progress: &on_progress };
+            let outcome = bsgs(&secp, &public_key(&secp, 3), &U256::from(0x40000), &U256::from(0x7ffff), 256, &search).unwrap();
+            assert_eq!(outcome, DlpOutcome::Exhausted);
+            total.into_inner()
+        };
+        // Fenêtres de 257 clés sans symétrie, de 513 avec : à peine plus que la plage dans les deux cas
+        let (plain, symmetric) = (covered(false), covered(true));
+        assert!(plain >= 0x40000 && symmetric >= 0x40000);
+        assert_eq!(plain.div_ceil(257), 0x40000u64.div_ceil(257));
+        assert!(symmetric.div_ceil(513) <= 0x40000u64.div_ceil(513) + 1);
+    }
+
+    #[test]
+    fn kangaroos_find_solved_puzzle_keys() {
+        let secp = Secp256k1::new();
+        // Puzzles 18 à 21
+        for (bits, key) in [(18, 0x3080d), (19, 0x5749f), (20, 0xd2c55), (21, 0x1ba534)] {
+            let (start, end) = (U256::from(1 << (bits - 1)), U256::from((1 << bits) - 1));
+            for symmetric in [false, true] {
+                let outcome = kangaroo(&secp, &public_key(&secp, key), &start, &end, &search(symmetric));
+                assert_eq!(outcome, DlpOutcome::Found(U256::from(key)), "puzzle {}, symétrie {}", bits, symmetric);
+            }
+        }
+    }
+
+    #[test]
+    fn collisions_between_opposite_wild_kangaroos_are_solved() {
+        let secp = Secp256k1::new();
+        let problem = Problem::new(&secp, &public_key(&secp, 1000 + 37), &U256::from(1000), &U256::from(1100), true);
+        assert_eq!(problem.base, ubig!(1050));
+        // Q' = -13·G : Q' + 20·G = 7·G = -(Q' + 6·G) ; apprivoisé 7·G contre sauvage Q' + 20·G
+        assert_eq!(problem.solve((1, U256::from(20)), (-1, neg_mod_n(U256::from(6)))), Some(U256::from(1037)));
+        assert_eq!(problem.solve((0, U256::from(7)), (1, U256::from(20))), Some(U256::from(1037)));
+        assert_eq!(problem.solve((1, U256::from(20)), (1, U256::from(20))), None);
+    }
+
+    #[test]
+    fn search_stops_on_request() {
+        let secp = Secp256k1::new();
+        let search = DlpSearch { threads: 1, symmetric: true, seed: 2, should_stop: &|| true, on_progress: &|_| {} };
+        let outcome = kangaroo(&secp, &public_key(&secp, 0x5749f), &U256::from(0x40000), &U256::from(0x7ffff), &search);
+        assert_eq!(outcome, DlpOutcome::Stopped);
+    }
+}
@@ -0,0 +1,269 @@
+// Arithmétique minimale sur secp256k1 pour la dérivation des clés par lots : éléments du corps premier
+// (4 mots de 64 bits) et points en coordonnées affines. Les additions d'un lot partagent une seule
+// inversion (astuce de Montgomery) ; les multiplications scalaires restent confiées à libsecp256k1.
+use bitcoin::secp256k1::PublicKey;
+
+// p = 2^256 - 2^32 - 977, en mots de poids croissant
+const P: [u64; 4] = [0xffff_fffe_ffff_fc2f, u64::MAX, u64::MAX, u64::MAX];
+// 2^256 mod p
+const R: u64 = 0x1_0000_03d1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FieldElement([u64; 4]);
+
+impl FieldElement {
+    pub const ZERO: Self = Self([0; 4]);
+    pub const ONE: Self = Self([1, 0, 0, 0]);
+
+    // Entier big-endian de 32 octets, réduit modulo p
+    pub fn from_be_bytes(bytes: &[u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let offset = 24 - 8 * i;
+            *limb = u64::from_be_bytes(bytes[offset..offset + 8].try_into().unwrap());
+        }
+        Self(limbs).reduced()
+    }
+
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, limb) in self.0.iter().enumerate() {
+            let offset = 24 - 8 * i;
+            bytes[offset..offset + 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    // 64 bits de poids faible (indexation et points distingués)
+    pub fn low_u64(&self) -> u64 {
+        self.0[0]
+    }
+
+    pub fn is_odd(&self) -> bool {
+        self.0[0] & 1 == 1
+    }
+
+    fn geq_p(&self) -> bool {
+        for i in (0..4).rev() {
+            if self.0[i] != P[i] {
+                return self.0[i] > P[i];
+            }
+        }
+        true
+    }
+
+    // Soustrait p si nécessaire (valeur < 2p)
+    fn reduced(self) -> Self {
+        if self.geq_p() { Self(sub_limbs(&self.0, &P).0) } else { self }
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let (sum, carry) = add_limbs(&self.0, &other.0);
+        if carry {
+            // a + b - p = (a + b - 2^256) + R, sans nouvelle retenue puisque a + b < 2p
+            Self(add_limbs(&sum, &[R, 0, 0, 0]).0)
+        } else {
+            Self(sum).reduced()
+        }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        let (difference, borrow) = sub_limbs(&self.0, &other.0);
+        if borrow {
+            // a - b + p = (a - b + 2^256) - R
+            Self(sub_limbs(&difference, &[R, 0, 0, 0]).0)
+        } else {
+            Self(difference)
+        }
+    }
+
+    pub fn neg(&self) -> Self {
+        Self::ZERO.sub(self)
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        // Produit complet sur 512 bits
+        let mut wide = [0u64; 8];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let t = wide[i + j] as u128 + self.0[i] as u128 * other.0[j] as u128 + carry;
+                wide[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            wide[i + 4] = carry as u64;
+        }
+        reduce_wide(&wide)
+    }
+
+    pub fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    // Inverse par le petit théorème de Fermat (a^(p-2)) ; l'inverse de 0 vaut 0
+    pub fn invert(&self) -> Self {
+        let mut exponent = P;
+        exponent[0] -= 2;
+        let mut result = Self::ONE;
+        for i in (0..256).rev() {
+            result = result.square();
+            if (exponent[i / 64] >> (i % 64)) & 1 == 1 {
+                result = result.mul(self);
+            }
+        }
+        result
+    }
+}
+
+fn add_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut out = [0u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (sum, c1) = a[i].overflowing_add(b[i]);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        out[i] = sum;
+        carry = c1 || c2;
+    }
+    (out, carry)
+}
+
+fn sub_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut out = [0u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (difference, b1) = a[i].overflowing_sub(b[i]);
+        let (difference, b2) = difference.overflowing_sub(borrow as u64);
+        out[i] = difference;
+        borrow = b1 || b2;
+    }
+    (out, borrow)
+}
+
+// Réduction modulo p d'un produit de 512 bits : haut·2^256 + bas ≡ haut·R + bas
+fn reduce_wide(wide: &[u64; 8]) -> FieldElement {
+    let mut folded = [0u64; 5];
+    let mut carry = 0u128;
+    for i in 0..4 {
+        let t = wide[i] as u128 + wide[i + 4] as u128 * R as u128 + carry;
+        folded[i] = t as u64;
+        carry = t >> 64;
+    }
+    folded[4] = carry as u64;
+
+    // Second repli du mot de poids fort (< 2^34), puis d'une éventuelle dernière retenue
+    let mut limbs = [0u64; 4];
+    let mut carry = folded[4] as u128 * R as u128;
+    for i in 0..4 {
+        let t = folded[i] as u128 + carry;
+        limbs[i] = t as u64;
+        carry = t >> 64;
+    }
+    if carry != 0 {
+        limbs = add_limbs(&limbs, &[R, 0, 0, 0]).0;
+    }
+    FieldElement(limbs).reduced()
+}
+
+// Point affine de la courbe (jamais le point à l'infini)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AffinePoint {
+    pub x: FieldElement,
+    pub y: FieldElement,
+}
+
+impl AffinePoint {
+    pub fn from_public_key(public_key: &PublicKey) -> Self {
+        let bytes = public_key.serialize_uncompressed();
+        Self {
+            x: FieldElement::from_be_bytes(bytes[1..33].try_into().unwrap()),
+            y: FieldElement::from_be_bytes(bytes[33..65].try_into().unwrap()),
+        }
+    }
+
+    pub fn negate(&self) -> Self {
+        Self { x: self.x, y: self.y.neg() }
+    }
+
+    pub fn serialize_compressed(&self) -> [u8; 33] {
+        let mut out = [0u8; 33];
+        out[0] = if self.y.is_odd() { 0x03 } else { 0x02 };
+        out[1..].copy_from_slice(&self.x.to_be_bytes());
+        out
+    }
+
+    pub fn serialize_uncompressed(&self) -> [u8; 65] {
+        let mut out = [0u8; 65];
+        out[0] = 0x04;
+        out[1..33].copy_from_slice(&self.x.to_be_bytes());
+        out[33..].copy_from_slice(&self.y.to_be_bytes());
+        out
+    }
+}
+
+// Sommes a_i + b_i de couples de points d'abscisses distinctes, avec une seule inversion pour tout le lot
+pub fn batch_add(pairs: &[(AffinePoint, AffinePoint)], out: &mut Vec<AffinePoint>) {
+    // Produits préfixes des dénominateurs x_b - x_a
+    let mut prefix = Vec::with_capacity(pairs.len());
+    let mut product = FieldElement::ONE;
+    for (a, b) in pairs {
+        prefix.push(product);
+        product = product.mul(&b.x.sub(&a.x));
+    }
+
+    // Inverses individuels en remontant le lot à partir de l'inverse du produit total
+    let mut inverse = product.invert();
+    let start = out.len();
+    out.resize(start + pairs.len(), AffinePoint { x: FieldElement::ZERO, y: FieldElement::ZERO });
+    for (i, (a, b)) in pairs.iter().enumerate().rev() {
+        let denominator = b.x.sub(&a.x);
+        let lambda = b.y.sub(&a.y).mul(&inverse.mul(&prefix[i]));
+        inverse = inverse.mul(&denominator);
+        let x = lambda.square().sub(&a.x).sub(&b.x);
+        let y = lambda.mul(&a.x.sub(&x)).sub(&a.y);
+        out[start + i] = AffinePoint { x, y };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::secret_key_from_ubig;
+    use bitcoin::secp256k1::Secp256k1;
+    use ibig::UBig;
+
+    fn point(key: u64) -> AffinePoint {
+        let secp = Secp256k1::new();
+        AffinePoint::from_public_key(&PublicKey::from_secret_key(&secp, &secret_key_from_ubig(&UBig::from(key)).unwrap()))
+    }
+
+    #[test]
+    fn field_operations_wrap_around_p() {
+        let minus_one = FieldElement::ONE.neg();
+        assert_eq!(minus_one.add(&FieldElement::ONE), FieldElement::ZERO);
+        assert_eq!(minus_one.mul(&minus_one), FieldElement::ONE);
+        assert_eq!(FieldElement::ZERO.sub(&minus_one), FieldElement::ONE);
+
+        let x = point(0x5749f).x;
+        assert_eq!(x.mul(&x.invert()), FieldElement::ONE);
+        assert_eq!(FieldElement::from_be_bytes(&x.to_be_bytes()), x);
+        // p lui-même se réduit à 0
+        assert_eq!(FieldElement::from_be_bytes(&FieldElement(P).to_be_bytes()), FieldElement::ZERO);
+    }
+
+    #[test]
+    fn batch_addition_matches_libsecp256k1() {
+        let pairs: Vec<_> = (1..40u64).map(|i| (point(1000 + i * 7919), point(i))).collect();
+        let mut sums = Vec::new();
+        batch_add(&pairs, &mut sums);
+        for (i, sum) in (1..40u64).zip(&sums) {
+            assert_eq!(*sum, point(1000 + i * 7919 + i));
+        }
+
+        let secp = Secp256k1::new();
+        let public_key = PublicKey::from_secret_key(&secp, &secret_key_from_ubig(&UBig::from(0x1764fu32)).unwrap());
+        let affine = AffinePoint::from_public_key(&public_key);
+        assert_eq!(affine.serialize_compressed(), public_key.serialize());
+        assert_eq!(affine.serialize_uncompressed(), public_key.serialize_uncompressed());
+        assert_eq!(affine.negate().y.add(&affine.y), FieldElement::ZERO);
+    }
+}
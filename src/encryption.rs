@@ -0,0 +1,300 @@
+// Chiffrement des enregistrements de résultats (age : clé publique X25519 ou phrase secrète)
+use age::secrecy::SecretString;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+// Variable d'environnement de la phrase secrète (jamais lue depuis config.txt ni depuis les arguments)
+pub const PASSPHRASE_ENV: &str = "RESULTS_PASSPHRASE";
+
+// Phrase secrète depuis l'environnement, sinon saisie sur l'entrée standard (deux fois avec `confirm`,
+// pour ne pas chiffrer des résultats avec une phrase mal tapée)
+pub fn read_passphrase(confirm: bool) -> std::io::Result<SecretString> {
+    if let Ok(value) = std::env::var(PASSPHRASE_ENV) {
+        if !value.is_empty() {
+            return Ok(SecretString::from(value));
+        }
+    }
+    let prompt = |label: &str| -> std::io::Result<String> {
+        eprint!("{} : ", label);
+        std::io::stderr().flush()?;
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)?;
+        Ok(line.trim_end_matches(['\r', '\n']).to_string())
+    };
+    let passphrase = prompt("Phrase secrète")?;
+    if passphrase.is_empty() {
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput,
+            format!("Phrase secrète vide : définissez {} ou saisissez-la au démarrage", PASSPHRASE_ENV)));
+    }
+    if confirm && prompt("Confirmation")? != passphrase {
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Les deux phrases secrètes diffèrent"));
+    }
+    Ok(SecretString::from(passphrase))
+}
+
+// Destinataire des enregistrements chiffrés
+pub enum ResultEncryption {
+    // Clé publique X25519 (age1...) : seul le détenteur de la clé privée peut déchiffrer
+    Recipient(age::x25519::Recipient),
+    // Clé dérivée d'une phrase secrète (scrypt)
+    Passphrase(SecretString),
+}
+
+impl ResultEncryption {
+    pub fn from_recipient(recipient: &str) -> Result<Self, String> {
+        age::x25519::Recipient::from_str(recipient)
+            .map(ResultEncryption::Recipient)
+            .map_err(|e| format!("Clé publique de chiffrement invalide '{}': {}", recipient, e))
+    }
+
+    pub fn from_passphrase(passphrase: &str) -> Self {
+        ResultEncryption::Passphrase(SecretString::from(passphrase.to_string()))
+    }
+
+    // Phrase secrète lue au démarrage (environnement ou saisie confirmée)
+    pub fn prompt_passphrase() -> Result<Self, String> {
+        read_passphrase(true)
+            .map(ResultEncryption::Passphrase)
+            .map_err(|e| format!("Phrase secrète de chiffrement indisponible : {}", e))
+    }
+
+    // Chiffre le texte clair et renvoie le message age au format ASCII armor
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<String, age::EncryptError> {
+        match self {
+            ResultEncryption::Recipient(recipient) => age::encrypt_and_armor(recipient, plaintext),
+            ResultEncryption::Passphrase(passphrase) => {
+                age::encrypt_and_armor(&age::scrypt::Recipient::new(passphrase.clone()), plaintext)
+            }
+        }
+    }
+}
+
+// Identités utilisées par la commande `decrypt-results`
+pub enum ResultDecryption {
+    IdentityFile(String),
+    Passphrase(SecretString),
+}
+
+impl ResultDecryption {
+    fn identities(&self) -> Result<Vec<Box<dyn age::Identity>>, Box<dyn std::error::Error>> {
+        match self {
+            ResultDecryption::IdentityFile(path) => {
+                Ok(age::IdentityFile::from_file(path.clone())?.into_identities()?)
+            }
+            ResultDecryption::Passphrase(passphrase) => {
+                Ok(vec![Box::new(age::scrypt::Identity::new(passphrase.clone()))])
+            }
+        }
+    }
+
+    pub fn decrypt(&self, armored: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let identities = self.identities()?;
+        let decryptor = age::Decryptor::new_buffered(age::armor::ArmoredReader::new(armored.as_bytes()))?;
+        let mut plaintext = Vec::new();
+        decryptor
+            .decrypt(identities.iter().map(|i| i.as_ref() as &dyn age::Identity))?
+            .read_to_end(&mut plaintext)?;
+        Ok(plaintext)
+    }
+}
+
+// Relit un fichier de résultats et écrit chaque enregistrement en clair sur `output`.
+// Les lignes non chiffrées sont recopiées telles quelles.
+pub fn decrypt_results_file(
+    path: &str,
+    decryption: &ResultDecryption,
+    output: &mut dyn std::io::Write,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let file = std::fs::File::open(path)?;
+    let mut decrypted = 0;
+
+    for (line_number, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let value: serde_json::Value = serde_json::from_str(&line)
+            .map_err(|e| format!("Ligne {} invalide: {}", line_number + 1, e))?;
+
+        match value.get("encrypted").and_then(|v| v.as_str()) {
+            Some(armored) => {
+                let plaintext = decryption
+                    .decrypt(armored)
+                    .map_err(|e| format!("Ligne {}: déchiffrement impossible: {}", line_number + 1, e))?;
+                output.write_all(&plaintext)?;
+                writeln!(output)?;
+                decrypted += 1;
+            }
+            None => writeln!(output, "{}", line)?,
+        }
+    }
+
+    Ok(decrypted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::{DispatcherSettings, Notification, NotificationDispatcher, Notifier, NotifyError};
+    use crate::results::{Backend, FoundKey, ResultHandler, ResultWriter};
+    use crate::{secret_key_from_ubig, Statistics};
+    use age::secrecy::ExposeSecret;
+    use bitcoin::secp256k1::Secp256k1;
+    use ibig::UBig;
+    use std::path::PathBuf;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("solver_encryption_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    // Clé 0xd2c55 (puzzle 20) : l'hexadécimal, le décimal et les WIF ne doivent jamais apparaître en clair.
+    // Des secrets trop courts ("d2c55") apparaîtraient par hasard dans le message chiffré ou l'horodatage.
+    fn record() -> FoundKey {
+        let secp = Secp256k1::new();
+        let key = UBig::from(0xd2c55u32);
+        let secret_key = secret_key_from_ubig(&key).unwrap();
+        let range = (UBig::from(0x80000u32), UBig::from(0xfffffu32));
+        FoundKey::from_match(&secp, &secret_key, &key, "1HsMJxNiV7TLxmoF6uJNkydxPFDog4NQum", Backend::Cpu, 3, "sequential", (&range.0, &range.1))
+    }
+
+    fn secrets(record: &FoundKey) -> [&str; 4] {
+        [&record.private_key_hex, &record.private_key_dec, &record.wif_compressed, &record.wif_uncompressed]
+    }
+
+    // Écrit l'enregistrement chiffré, vérifie qu'aucun secret n'est lisible puis le déchiffre
+    fn round_trip(name: &str, encryption: ResultEncryption, decryption: ResultDecryption) {
+        let dir = temp_dir(name);
+        let (results, summary) = (dir.join("found.jsonl"), dir.join("found.txt"));
+        let writer = ResultWriter::new(results.to_str().unwrap(), summary.to_str(), Some(encryption));
+        let record = record();
+        writer.write(&record).unwrap();
+
+        let written = std::fs::read_to_string(&results).unwrap();
+        let summary_line = std::fs::read_to_string(&summary).unwrap();
+        for secret in secrets(&record) {
+            assert!(!written.contains(secret), "{} lisible dans les résultats", secret);
+            assert!(!summary_line.contains(secret), "{} lisible dans le résumé", secret);
+        }
+        let envelope: serde_json::Value = serde_json::from_str(written.trim()).unwrap();
+        assert_eq!(envelope["target"], record.target);
+        assert!(summary_line.contains(&record.target));
+
+        let mut output = Vec::new();
+        assert_eq!(decrypt_results_file(results.to_str().unwrap(), &decryption, &mut output).unwrap(), 1);
+        let decrypted: FoundKey = serde_json::from_slice(&output).unwrap();
+        assert_eq!(decrypted.private_key_hex, "d2c55");
+        assert_eq!(decrypted.private_key_dec, "863317");
+        assert_eq!(decrypted.wif_compressed, record.wif_compressed);
+        assert_eq!(decrypted.wif_uncompressed, record.wif_uncompressed);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn recipient_encrypted_results_round_trip() {
+        let identity = age::x25519::Identity::generate();
+        let dir = temp_dir("identity");
+        let identity_file = dir.join("identity.txt");
+        std::fs::write(&identity_file, identity.to_string().expose_secret()).unwrap();
+
+        let encryption = ResultEncryption::from_recipient(&identity.to_public().to_string()).unwrap();
+        let decryption = ResultDecryption::IdentityFile(identity_file.to_str().unwrap().to_string());
+        round_trip("recipient", encryption, decryption);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn passphrase_encrypted_results_round_trip() {
+        let encryption = ResultEncryption::from_passphrase("phrase de test");
+        let decryption = ResultDecryption::Passphrase(SecretString::from("phrase de test".to_string()));
+        round_trip("passphrase", encryption, decryption);
+    }
+
+    #[test]
+    fn invalid_recipient_is_rejected() {
+        assert!(ResultEncryption::from_recipient("age1invalide").is_err());
+    }
+
+    struct RecordingNotifier(Arc<Mutex<Vec<Notification>>>);
+
+    impl Notifier for RecordingNotifier {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+            self.0.lock().unwrap().push(notification.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn encrypted_hits_are_notified_redacted() {
+        let dir = temp_dir("notified");
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let settings = DispatcherSettings {
+            max_retries: 0,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(10),
+            outbox_file: dir.join("outbox.jsonl").to_str().unwrap().to_string(),
+            shutdown_grace: Duration::from_secs(5),
+        };
+        let notifications = Arc::new(NotificationDispatcher::start(vec![Box::new(RecordingNotifier(received.clone()))], settings));
+        let encryption = ResultEncryption::from_recipient(&age::x25519::Identity::generate().to_public().to_string()).unwrap();
+        let writer = ResultWriter::new(dir.join("found.jsonl").to_str().unwrap(), None, Some(encryption));
+        let handler = ResultHandler::new(writer, notifications.clone(), Arc::new(Statistics::new()), Arc::new(AtomicBool::new(false)));
+
+        let record = record();
+        handler.handle_hit(record.clone());
+        notifications.shutdown();
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        let Notification::KeyFound { record: notified } = &received[0] else { panic!("notification inattendue") };
+        assert_eq!(serde_json::to_value(notified).unwrap(), serde_json::to_value(record.redacted()).unwrap());
+        assert!(secrets(notified).iter().all(|secret| secret.is_empty()));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn unwritable_results_file_falls_back_to_the_rescue_file() {
+        let dir = temp_dir("rescue");
+        let identity = age::x25519::Identity::generate();
+        let identity_file = dir.join("identity.txt");
+        std::fs::write(&identity_file, identity.to_string().expose_secret()).unwrap();
+        let settings = DispatcherSettings {
+            max_retries: 0,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(10),
+            outbox_file: dir.join("outbox.jsonl").to_str().unwrap().to_string(),
+            shutdown_grace: Duration::from_secs(5),
+        };
+        let notifications = Arc::new(NotificationDispatcher::start(Vec::new(), settings));
+        let encryption = ResultEncryption::from_recipient(&identity.to_public().to_string()).unwrap();
+        // Le fichier de résultats est dans un répertoire inexistant : toutes les écritures échouent
+        let results = dir.join("absent").join("found.jsonl");
+        let rescue = dir.join("rescue.jsonl");
+        let writer = ResultWriter::new(results.to_str().unwrap(), None, Some(encryption)).with_rescue_file(rescue.to_str().unwrap());
+        let handler = ResultHandler::new(writer, notifications.clone(), Arc::new(Statistics::new()), Arc::new(AtomicBool::new(false)));
+
+        let record = record();
+        handler.handle_hit(record.clone());
+        notifications.shutdown();
+
+        assert!(!results.exists());
+        let rescued = std::fs::read_to_string(&rescue).unwrap();
+        assert!(secrets(&record).iter().all(|secret| !rescued.contains(secret)));
+        let mut output = Vec::new();
+        let decryption = ResultDecryption::IdentityFile(identity_file.to_str().unwrap().to_string());
+        assert_eq!(decrypt_results_file(rescue.to_str().unwrap(), &decryption, &mut output).unwrap(), 1);
+        let decrypted: FoundKey = serde_json::from_slice(&output).unwrap();
+        assert_eq!(decrypted.private_key_hex, "d2c55");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,309 @@
+// Journal d'événements structuré (JSON Lines) : un objet JSON par ligne, schéma stable
+// destiné aux outils d'analyse (analyzer_script.py, sous-commande `report`)
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use crate::monitor::ProgressReport;
+use crate::results::Backend;
+use crate::Config;
+
+// Incrémenté à chaque changement incompatible du format des événements
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl std::str::FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "debug" => Ok(Level::Debug),
+            "info" => Ok(Level::Info),
+            "warn" | "warning" => Ok(Level::Warn),
+            "error" => Ok(Level::Error),
+            _ => Err(format!("Niveau de journal inconnu: {}", s)),
+        }
+    }
+}
+
+// Instantané d'un worker dans les événements `stats`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerSnapshot {
+    pub backend: Backend,
+    pub worker: usize,
+    pub range_start: String,
+    pub range_end: String,
+    pub position: String,
+    pub keys_checked: u64,
+    pub rate: f64,
+    pub stalled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    Start {
+        version: String,
+        pid: u32,
+    },
+    Config {
+        mode: String,
+        compute_mode: String,
+        range_start: String,
+        range_end: String,
+        range_size: String,
+        threads: usize,
+        targets: usize,
+        // Absente des journaux antérieurs à l'option `seed`
+        #[serde(default)]
+        seed: Option<u64>,
+    },
+    Stats {
+        #[serde(flatten)]
+        report: ProgressReport,
+        workers: Vec<WorkerSnapshot>,
+    },
+    Checkpoint {
+        backend: Backend,
+        worker: usize,
+        position: String,
+    },
+    // Jamais de clé privée dans le journal : elle reste dans le fichier de résultats
+    Hit {
+        target: String,
+        backend: Backend,
+        worker: usize,
+        address_type: String,
+    },
+    WorkerExit {
+        backend: Backend,
+        worker: usize,
+        reason: String,
+        keys_checked: u64,
+        position: String,
+    },
+    Alert {
+        message: String,
+    },
+    End {
+        elapsed_secs: u64,
+        keys_checked: u64,
+        average_rate: f64,
+        found: u64,
+    },
+}
+
+// Ligne complète du journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventRecord {
+    pub schema: u32,
+    pub ts: String,
+    pub level: Level,
+    pub run_id: String,
+    #[serde(flatten)]
+    pub event: Event,
+}
+
+struct OpenLog {
+    file: File,
+    size: u64,
+}
+
+pub struct EventLog {
+    path: String,
+    min_level: Level,
+    max_bytes: u64,
+    keep: usize,
+    run_id: String,
+    file: Mutex<Option<OpenLog>>,
+    write_failed: AtomicBool,
+}
+
+impl EventLog {
+    pub fn open(path: &str, min_level: Level, max_bytes: u64, keep: usize) -> std::io::Result<Self> {
+        let log = Self {
+            path: path.to_string(),
+            min_level,
+            max_bytes,
+            keep,
+            run_id: format!("{}-{}", chrono::Local::now().format("%Y%m%dT%H%M%S"), std::process::id()),
+            file: Mutex::new(None),
+            write_failed: AtomicBool::new(false),
+        };
+        *log.file.lock().unwrap_or_else(|e| e.into_inner()) = Some(log.open_file()?);
+        Ok(log)
+    }
+
+    pub fn enabled(&self, level: Level) -> bool {
+        level >= self.min_level
+    }
+
+    fn open_file(&self) -> std::io::Result<OpenLog> {
+        let file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        let size = file.metadata()?.len();
+        Ok(OpenLog { file, size })
+    }
+
+    // solver_events.jsonl -> solver_events.jsonl.1 -> ... -> solver_events.jsonl.<keep>
+    fn rotate(&self) -> std::io::Result<OpenLog> {
+        if self.keep == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let _ = fs::remove_file(format!("{}.{}", self.path, self.keep));
+            for i in (1..self.keep).rev() {
+                let _ = fs::rename(format!("{}.{}", self.path, i), format!("{}.{}", self.path, i + 1));
+            }
+            fs::rename(&self.path, format!("{}.1", self.path))?;
+        }
+        self.open_file()
+    }
+
+    pub fn write(&self, level: Level, event: Event) {
+        if !self.enabled(level) {
+            return;
+        }
+        let record = EventRecord {
+            schema: SCHEMA_VERSION,
+            ts: chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, false),
+            level,
+            run_id: self.run_id.clone(),
+            event,
+        };
+        let Ok(mut line) = serde_json::to_string(&record) else { return };
+        line.push('\n');
+
+        let mut guard = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        let result = (|| {
+            let needs_rotation = guard.as_ref().is_some_and(|log| log.size > 0 && log.size + line.len() as u64 > self.max_bytes);
+            if needs_rotation || guard.is_none() {
+                *guard = None;
+                *guard = Some(if needs_rotation { self.rotate()? } else { self.open_file()? });
+            }
+            let log = guard.as_mut().expect("journal ouvert");
+            log.file.write_all(line.as_bytes())?;
+            log.size += line.len() as u64;
+            Ok::<(), std::io::Error>(())
+        })();
+
+        if let Err(e) = result {
+            *guard = None;
+            if !self.write_failed.swap(true, Ordering::Relaxed) {
+                eprintln!("⚠️  Impossible d'écrire le journal d'événements '{}': {}", self.path, e);
+            }
+        }
+    }
+}
+
+static EVENT_LOG: OnceLock<EventLog> = OnceLock::new();
+
+// Active le journal global si `event_log` est configuré
+pub fn init(config: &Config) -> Result<bool, String> {
+    let Some(path) = &config.event_log else { return Ok(false) };
+    let log = EventLog::open(path, config.event_log_level, config.event_log_max_bytes, config.event_log_keep)
+        .map_err(|e| format!("Impossible d'ouvrir le journal d'événements '{}': {}", path, e))?;
+    let _ = EVENT_LOG.set(log);
+    Ok(true)
+}
+
+pub fn enabled(level: Level) -> bool {
+    EVENT_LOG.get().is_some_and(|log| log.enabled(level))
+}
+
+// Sans effet lorsque le journal n'est pas configuré
+pub fn emit(level: Level, event: Event) {
+    if let Some(log) = EVENT_LOG.get() {
+        log.write(level, event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_log(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("solver_events_test_{}_{}.jsonl", name, std::process::id()));
+        for i in 0..4 {
+            let _ = fs::remove_file(if i == 0 { path.clone() } else { PathBuf::from(format!("{}.{}", path.display(), i)) });
+        }
+        path
+    }
+
+    fn read_records(path: &str) -> Vec<EventRecord> {
+        fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn records_round_trip_and_respect_level() {
+        let path = temp_log("levels");
+        let path = path.to_str().unwrap();
+        let log = EventLog::open(path, Level::Info, 1 << 20, 2).unwrap();
+        log.write(Level::Debug, Event::Checkpoint { backend: Backend::Cpu, worker: 0, position: "0x10".into() });
+        log.write(Level::Info, Event::Hit {
+            target: "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH".into(),
+            backend: Backend::Gpu,
+            worker: 2,
+            address_type: "p2pkh_compressed".into(),
+        });
+
+        let records = read_records(path);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].schema, SCHEMA_VERSION);
+        assert_eq!(records[0].level, Level::Info);
+        assert!(matches!(&records[0].event, Event::Hit { worker: 2, .. }));
+
+        let raw: serde_json::Value = serde_json::from_str(fs::read_to_string(path).unwrap().lines().next().unwrap()).unwrap();
+        assert_eq!(raw["event"], "hit");
+        assert_eq!(raw["backend"], "gpu");
+    }
+
+    #[test]
+    fn stats_event_flattens_report() {
+        let path = temp_log("stats");
+        let path = path.to_str().unwrap();
+        let log = EventLog::open(path, Level::Debug, 1 << 20, 2).unwrap();
+        let stats = crate::Statistics::new();
+        stats.add_cpu_keys(500);
+        let report = ProgressReport::collect(&stats, &ibig::UBig::from(1000u32), crate::monitor::Coverage::Random);
+        log.write(Level::Info, Event::Stats { report, workers: Vec::new() });
+
+        let raw: serde_json::Value = serde_json::from_str(fs::read_to_string(path).unwrap().trim()).unwrap();
+        assert_eq!(raw["event"], "stats");
+        assert_eq!(raw["keys_checked"], 500);
+        match &read_records(path)[0].event {
+            Event::Stats { report, .. } => assert_eq!(report.keys_checked, 500),
+            other => panic!("événement inattendu: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn log_rotates_when_full() {
+        let path = temp_log("rotation");
+        let path = path.to_str().unwrap();
+        let log = EventLog::open(path, Level::Debug, 300, 2).unwrap();
+        for i in 0..20 {
+            log.write(Level::Info, Event::Alert { message: format!("message {}", i) });
+        }
+
+        assert!(fs::metadata(path).unwrap().len() <= 300);
+        assert!(fs::metadata(format!("{}.1", path)).is_ok());
+        assert!(fs::metadata(format!("{}.2", path)).is_ok());
+        assert!(fs::metadata(format!("{}.3", path)).is_err());
+        let last = read_records(path).pop().unwrap();
+        assert!(matches!(last.event, Event::Alert { ref message } if message == "message 19"));
+    }
+}
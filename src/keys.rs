@@ -0,0 +1,96 @@
+// Vérification des clés : dérivation des adresses P2PKH d'une clé privée
+use bitcoin::secp256k1::{self, All, Secp256k1, SecretKey};
+use bitcoin::{Address, Network, PrivateKey, PublicKey};
+use ibig::UBig;
+
+use crate::targets::TargetSet;
+use crate::u256::U256;
+
+// Clé privée secp256k1 correspondant à l'entier (None pour 0, hors de l'ordre de la courbe ou > 256 bits)
+pub fn secret_key_from_ubig(key: &UBig) -> Option<SecretKey> {
+    let key_bytes = key.to_be_bytes();
+    if key_bytes.len() > 32 {
+        return None;
+    }
+    let mut padded = [0u8; 32];
+    padded[32 - key_bytes.len()..].copy_from_slice(&key_bytes);
+    SecretKey::from_slice(&padded).ok()
+}
+
+// Même conversion pour une clé sur 256 bits, sans allocation
+pub fn secret_key_from_u256(key: &U256) -> Option<SecretKey> {
+    SecretKey::from_slice(&key.to_be_bytes()).ok()
+}
+
+pub fn generate_address_variants(secp: &Secp256k1<All>, secret_key: &SecretKey) -> Vec<(PrivateKey, Address)> {
+    let mut variants = Vec::new();
+    
+    // Version compressée
+    let private_key_compressed = PrivateKey {
+        compressed: true,
+        network: Network::Bitcoin.into(),
+        inner: *secret_key,
+    };
+    let public_key_compressed = private_key_compressed.public_key(secp);
+    let address_compressed = Address::p2pkh(public_key_compressed, Network::Bitcoin);
+    variants.push((private_key_compressed, address_compressed));
+    
+    // Version non compressée
+    let private_key_uncompressed = PrivateKey {
+        compressed: false,
+        network: Network::Bitcoin.into(),
+        inner: *secret_key,
+    };
+    let public_key_uncompressed = private_key_uncompressed.public_key(secp);
+    let address_uncompressed = Address::p2pkh(public_key_uncompressed, Network::Bitcoin);
+    variants.push((private_key_uncompressed, address_uncompressed));
+    
+    variants
+}
+
+// Adresses (compressée / non compressée) de la clé présentes dans l'ensemble recherché
+pub fn matching_addresses(secp: &Secp256k1<All>, secret_key: &SecretKey, targets: &TargetSet) -> Vec<String> {
+    generate_address_variants(secp, secret_key)
+        .into_iter()
+        .map(|(_, address)| address.to_string())
+        .filter(|address| targets.contains(address))
+        .collect()
+}
+
+// Adresses P2PKH (compressée puis non compressée) d'une clé publique déjà calculée
+pub fn public_key_addresses(public_key: &secp256k1::PublicKey) -> [Address; 2] {
+    [true, false].map(|compressed| Address::p2pkh(PublicKey { compressed, inner: *public_key }, Network::Bitcoin))
+}
+
+// Variante de `matching_addresses` pour une clé publique obtenue par addition de points
+pub fn matching_public_key_addresses(public_key: &secp256k1::PublicKey, targets: &TargetSet) -> Vec<String> {
+    public_key_addresses(public_key)
+        .into_iter()
+        .map(|address| address.to_string())
+        .filter(|address| targets.contains(address))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ibig::ubig;
+
+    #[test]
+    fn point_addition_matches_scalar_multiplication() {
+        let secp = Secp256k1::new();
+        let public = |key: &UBig| secp256k1::PublicKey::from_secret_key(&secp, &secret_key_from_ubig(key).unwrap());
+        let step = public(&ubig!(7));
+        let mut point = public(&ubig!(0x1764f));
+        for i in 1..50u32 {
+            point = point.combine(&step).unwrap();
+            assert_eq!(point, public(&(ubig!(0x1764f) + UBig::from(7 * i))));
+        }
+
+        let secret_key = secret_key_from_ubig(&ubig!(0x1764f)).unwrap();
+        let expected: Vec<String> = generate_address_variants(&secp, &secret_key).into_iter().map(|(_, a)| a.to_string()).collect();
+        let addresses: Vec<String> = public_key_addresses(&public(&ubig!(0x1764f))).iter().map(|a| a.to_string()).collect();
+        assert_eq!(addresses, expected);
+        assert_eq!(addresses[0], "1HduPEXZRdG26SUT5Yk83mLkPyjnZuJ7Bm");
+    }
+}
@@ -0,0 +1,34 @@
+// Bibliothèque du solveur de puzzle Bitcoin : vérification des clés, ensembles d'adresses cibles,
+// découpage des plages et stratégies de recherche. Le binaire n'est qu'une interface autour de `solver::run_search`.
+pub mod api;
+pub mod batch;
+pub mod checkpoint;
+pub mod config;
+pub mod dlp;
+pub mod ec;
+pub mod encryption;
+pub mod events;
+pub mod gpu;
+pub mod keys;
+pub mod metrics;
+pub mod monitor;
+pub mod notify;
+pub mod rates;
+pub mod report;
+pub mod results;
+pub mod schedule;
+pub mod solver;
+pub mod stats;
+pub mod strategy;
+pub mod targets;
+pub mod u256;
+pub mod worker;
+
+pub use config::{ComputeMode, Config};
+pub use keys::{generate_address_variants, matching_addresses, secret_key_from_u256, secret_key_from_ubig};
+pub use schedule::split_range;
+pub use solver::{run_search, SearchOutcome};
+pub use stats::{Control, Statistics, WorkerStats};
+pub use strategy::{generate_key_patterns, FastRng, PatternFamily};
+pub use targets::TargetSet;
+pub use u256::U256;
@@ -0,0 +1,104 @@
+// Export des compteurs au format texte Prometheus (route /metrics de l'API HTTP)
+use std::fmt::Write;
+use std::sync::atomic::Ordering;
+
+use crate::notify::DispatchCounters;
+use crate::{Control, Statistics};
+
+struct MetricsWriter {
+    output: String,
+}
+
+impl MetricsWriter {
+    fn header(&mut self, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(self.output, "# HELP {} {}", name, help);
+        let _ = writeln!(self.output, "# TYPE {} {}", name, kind);
+    }
+
+    fn sample(&mut self, name: &str, labels: &[(&str, String)], value: f64) {
+        if labels.is_empty() {
+            let _ = writeln!(self.output, "{} {}", name, value);
+        } else {
+            let labels: Vec<String> = labels.iter().map(|(k, v)| format!("{}=\"{}\"", k, v)).collect();
+            let _ = writeln!(self.output, "{}{{{}}} {}", name, labels.join(","), value);
+        }
+    }
+}
+
+pub fn render_metrics(stats: &Statistics, control: &Control, notifications: &DispatchCounters) -> String {
+    let mut m = MetricsWriter { output: String::new() };
+    let workers = stats.workers();
+    let worker_labels = |backend: String, id: usize| vec![("backend", backend), ("worker", id.to_string())];
+
+    m.header("solver_uptime_seconds", "gauge", "Temps écoulé depuis le démarrage de la recherche.");
+    m.sample("solver_uptime_seconds", &[], stats.start_time.elapsed().as_secs_f64());
+
+    m.header("solver_paused", "gauge", "1 si la recherche est en pause.");
+    m.sample("solver_paused", &[], if control.paused.load(Ordering::Relaxed) { 1.0 } else { 0.0 });
+
+    m.header("solver_backend_keys_checked_total", "counter", "Clés testées par type de worker.");
+    m.sample("solver_backend_keys_checked_total", &[("backend", "cpu".to_string())],
+        stats.cpu_keys_checked.load(Ordering::Relaxed) as f64);
+    m.sample("solver_backend_keys_checked_total", &[("backend", "gpu".to_string())],
+        stats.gpu_keys_checked.load(Ordering::Relaxed) as f64);
+
+    m.header("solver_keys_checked_total", "counter", "Clés testées par worker.");
+    for w in &workers {
+        m.sample("solver_keys_checked_total", &worker_labels(w.backend.to_string().to_lowercase(), w.id),
+            w.keys_checked.load(Ordering::Relaxed) as f64);
+    }
+
+    m.header("solver_hits_total", "counter", "Clés trouvées par worker.");
+    for w in &workers {
+        m.sample("solver_hits_total", &worker_labels(w.backend.to_string().to_lowercase(), w.id),
+            w.hits.load(Ordering::Relaxed) as f64);
+    }
+
+    m.header("solver_checkpoints_written_total", "counter", "Points de contrôle écrits par worker.");
+    for w in &workers {
+        m.sample("solver_checkpoints_written_total", &worker_labels(w.backend.to_string().to_lowercase(), w.id),
+            w.checkpoints_written.load(Ordering::Relaxed) as f64);
+    }
+
+    m.header("solver_worker_position", "gauge", "Position courante du worker dans l'espace des clés.");
+    m.header("solver_worker_position_ratio", "gauge", "Position courante rapportée à la plage du worker (0 - 1).");
+    for w in &workers {
+        let labels = worker_labels(w.backend.to_string().to_lowercase(), w.id);
+        m.sample("solver_worker_position", &labels, w.position().to_f64());
+        m.sample("solver_worker_position_ratio", &labels, w.position_ratio());
+    }
+
+    m.header("solver_worker_rate", "gauge", "Vitesse du worker (clés/s) sur une fenêtre glissante.");
+    for w in &workers {
+        for (window, rate) in w.rates().windows() {
+            let mut labels = worker_labels(w.backend.to_string().to_lowercase(), w.id);
+            labels.push(("window", window.to_string()));
+            m.sample("solver_worker_rate", &labels, rate);
+        }
+    }
+
+    m.header("solver_worker_peak_rate", "gauge", "Vitesse maximale observée sur la fenêtre courte (clés/s).");
+    for w in &workers {
+        m.sample("solver_worker_peak_rate", &worker_labels(w.backend.to_string().to_lowercase(), w.id), w.rates().peak());
+    }
+
+    m.header("solver_worker_stalled", "gauge", "1 si le compteur du worker n'avance plus.");
+    for w in &workers {
+        m.sample("solver_worker_stalled", &worker_labels(w.backend.to_string().to_lowercase(), w.id),
+            if w.is_stalled() { 1.0 } else { 0.0 });
+    }
+
+    m.header("solver_notifications_delivered_total", "counter", "Notifications livrées.");
+    m.sample("solver_notifications_delivered_total", &[], notifications.delivered.load(Ordering::Relaxed) as f64);
+
+    m.header("solver_notification_failures_total", "counter", "Tentatives de livraison de notification échouées.");
+    m.sample("solver_notification_failures_total", &[], notifications.failed_attempts.load(Ordering::Relaxed) as f64);
+
+    m.header("solver_notifications_persisted_total", "counter", "Notifications abandonnées et conservées dans l'outbox.");
+    m.sample("solver_notifications_persisted_total", &[], notifications.persisted.load(Ordering::Relaxed) as f64);
+
+    m.header("solver_notifications_dropped_total", "counter", "Notifications perdues : ni livrées, ni écrites dans l'outbox.");
+    m.sample("solver_notifications_dropped_total", &[], notifications.dropped.load(Ordering::Relaxed) as f64);
+
+    m.output
+}
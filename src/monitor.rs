@@ -0,0 +1,372 @@
+// Surveillance de la recherche : rapports d'avancement périodiques et alertes « homme mort »
+use ibig::UBig;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::events::{self, Event, Level};
+use crate::notify::{Notification, NotificationDispatcher};
+use crate::results::Backend;
+use crate::strategy::SearchMode;
+use crate::{Config, Statistics};
+
+// Instantané de l'avancement, envoyé tel quel aux notificateurs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressReport {
+    pub timestamp: String,
+    pub elapsed_secs: u64,
+    pub keys_checked: u64,
+    pub rate: f64,
+    pub cpu_rate: f64,
+    pub gpu_rate: f64,
+    // Taille exacte de la plage configurée (hex)
+    pub range_size: String,
+    // Séquentiel et masque : fraction réellement parcourue ; aléatoire : fraction de clés distinctes attendue
+    pub coverage: f64,
+    // Probabilité cumulée d'avoir déjà testé une clé placée uniformément dans la plage
+    pub success_probability: f64,
+    // Clés restant à parcourir (modes séquentiel et mask uniquement, valeur exacte en hex)
+    pub remaining_keys: Option<String>,
+    // Temps restant estimé pour parcourir toute la plage (modes séquentiel et mask uniquement)
+    pub eta_secs: Option<f64>,
+    // Temps estimé avant d'atteindre 50 % de probabilité de succès
+    pub eta_50_secs: Option<f64>,
+    pub found: u64,
+}
+
+// Façon d'estimer l'avancement selon le mode de recherche
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Coverage {
+    // Parcours dans l'ordre : clés restantes d'après la position de chaque worker CPU
+    Positions,
+    // Énumération sans répétition dans un autre ordre (masque, sous-intervalles) : clés restantes = total - clés testées
+    Exhaustive,
+    // Tirages aléatoires : couverture attendue
+    Random,
+}
+
+impl Coverage {
+    pub fn for_config(config: &Config) -> Self {
+        match config.mode {
+            SearchMode::Sequential => Coverage::Positions,
+            // Le mode bsgs couvre la plage pas de géant après pas de géant, sans répétition ; le mode stride
+            // parcourt chaque terme de sa progression une fois (la taille de recherche compte les termes) et le
+            // mode shuffle chaque bloc de la permutation une fois
+            SearchMode::Mask | SearchMode::Bsgs | SearchMode::Stride | SearchMode::Shuffle => Coverage::Exhaustive,
+            // Les candidats du mode smart sortent des sous-intervalles : seul le mode random est sans répétition
+            SearchMode::Random if config.subinterval_ratio > 0.0 => Coverage::Exhaustive,
+            _ => Coverage::Random,
+        }
+    }
+}
+
+// Fenêtre de vitesse utilisée pour les estimations de durée (plus stable que la fenêtre courte)
+const ESTIMATE_WINDOW: Duration = Duration::from_secs(60);
+
+impl ProgressReport {
+    // `range_size` : nombre de clés à parcourir (clés compatibles avec le masque en mode mask)
+    pub fn collect(stats: &Statistics, range_size: &UBig, model: Coverage) -> Self {
+        let keys_checked = stats.keys_checked.load(Ordering::Relaxed);
+        let rate = stats.get_rate();
+        let estimate_rate = stats.rate_trackers()[0].rate(ESTIMATE_WINDOW).unwrap_or(rate);
+        let size = range_size.to_f64();
+
+        let remaining = match model {
+            Coverage::Positions => Some(sequential_remaining(stats, range_size, keys_checked)),
+            Coverage::Exhaustive => Some(range_size - UBig::from(keys_checked).min(range_size.clone())),
+            Coverage::Random => None,
+        };
+        let coverage = match &remaining {
+            Some(remaining) => 1.0 - remaining.to_f64() / size,
+            None => expected_random_coverage(keys_checked as f64, size),
+        };
+
+        // Pour une clé unique placée uniformément, la probabilité de l'avoir rencontrée
+        // est exactement la fraction de clés distinctes déjà testées
+        let success_probability = coverage;
+        let keys_to_half = if remaining.is_some() {
+            (0.5 - coverage) * size
+        } else {
+            keys_for_probability(0.5, size) - keys_checked as f64
+        };
+
+        let eta_secs = remaining
+            .as_ref()
+            .filter(|_| estimate_rate > 0.0)
+            .map(|remaining| remaining.to_f64() / estimate_rate);
+        let eta_50_secs = (estimate_rate > 0.0 && success_probability < 0.5)
+            .then(|| keys_to_half.max(0.0) / estimate_rate);
+
+        Self {
+            timestamp: chrono::Local::now().to_rfc3339(),
+            elapsed_secs: stats.start_time.elapsed().as_secs(),
+            keys_checked,
+            rate,
+            cpu_rate: stats.get_cpu_rate(),
+            gpu_rate: stats.get_gpu_rate(),
+            range_size: format!("0x{:x}", range_size),
+            coverage,
+            success_probability,
+            remaining_keys: remaining.map(|remaining| format!("0x{:x}", remaining)),
+            eta_secs,
+            eta_50_secs,
+            found: stats.found_count.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            format!("Temps écoulé: {}", format_duration(self.elapsed_secs)),
+            format!("Vitesse: {:.2} Mk/s (CPU {:.2} | GPU {:.2})",
+                self.rate / 1_000_000.0, self.cpu_rate / 1_000_000.0, self.gpu_rate / 1_000_000.0),
+            format!("Clés testées: {}", self.keys_checked),
+        ];
+        match &self.remaining_keys {
+            Some(remaining) => {
+                lines.push(format!("Couverture: {:.6}%", self.coverage * 100.0));
+                lines.push(format!("Clés restantes: {}", remaining));
+            },
+            None => lines.push(format!("Couverture attendue: {:.6}%", self.coverage * 100.0)),
+        }
+        lines.push(format!("Probabilité de succès: {:.6}%", self.success_probability * 100.0));
+        if let Some(eta) = self.eta_secs {
+            lines.push(format!("Fin estimée dans: {}", format_eta(eta)));
+        }
+        if let Some(eta) = self.eta_50_secs {
+            lines.push(format!("50 % de chances dans: {}", format_eta(eta)));
+        }
+        lines.push(format!("Trouvées: {}", self.found));
+        lines
+    }
+}
+
+// Clés restant à parcourir en mode séquentiel, d'après la position de chaque worker CPU
+// (les points de contrôle repris sont donc comptés comme déjà parcourus)
+fn sequential_remaining(stats: &Statistics, range_size: &UBig, keys_checked: u64) -> UBig {
+    let workers: Vec<_> = stats.workers().into_iter().filter(|w| w.backend == Backend::Cpu).collect();
+    if workers.is_empty() {
+        let checked = UBig::from(keys_checked).min(range_size.clone());
+        return range_size - checked;
+    }
+
+    let one = UBig::from(1u8);
+    workers.iter().fold(UBig::from(0u8), |total, worker| {
+        let position = worker.position();
+        let end = &worker.range_end + &one;
+        if position < end { total + (end - position) } else { total }
+    })
+}
+
+// Tirages uniformes avec remise : fraction attendue de clés distinctes après n tirages
+// parmi N, soit 1 - (1 - 1/N)^n ≈ 1 - e^(-n/N) (exp_m1 conserve la précision pour n << N)
+pub fn expected_random_coverage(draws: f64, range_size: f64) -> f64 {
+    if range_size <= 0.0 {
+        return 0.0;
+    }
+    -(-draws / range_size).exp_m1()
+}
+
+// Nombre de tirages aléatoires nécessaires pour atteindre la probabilité p
+pub fn keys_for_probability(p: f64, range_size: f64) -> f64 {
+    -range_size * (-p).ln_1p()
+}
+
+// Les durées dépassant l'âge de l'univers ne sont pas lisibles en jours
+pub fn format_eta(secs: f64) -> String {
+    const YEAR: f64 = 365.25 * 86400.0;
+    if secs >= 1000.0 * YEAR {
+        format!("{:.2e} ans", secs / YEAR)
+    } else {
+        format_duration(secs as u64)
+    }
+}
+
+pub fn format_duration(secs: u64) -> String {
+    if secs >= 86400 {
+        format!("{}j {:02}:{:02}:{:02}", secs / 86400, (secs % 86400) / 3600, (secs % 3600) / 60, secs % 60)
+    } else {
+        format!("{:02}:{:02}:{:02}", secs / 3600, (secs % 3600) / 60, secs % 60)
+    }
+}
+
+pub struct Monitor {
+    range_size: UBig,
+    coverage: Coverage,
+    progress_interval: Option<Duration>,
+    heartbeat_timeout: Option<Duration>,
+    worker_stall_timeout: Option<Duration>,
+    last_progress: Instant,
+    last_count: u64,
+    last_advance: Instant,
+    stalled: bool,
+}
+
+impl Monitor {
+    pub fn new(config: &Config, range_size: &UBig) -> Self {
+        let now = Instant::now();
+        Self {
+            range_size: range_size.clone(),
+            coverage: Coverage::for_config(config),
+            progress_interval: (config.progress_notify_interval > 0)
+                .then(|| Duration::from_secs(config.progress_notify_interval)),
+            heartbeat_timeout: (config.heartbeat_timeout > 0)
+                .then(|| Duration::from_secs(config.heartbeat_timeout)),
+            worker_stall_timeout: (config.worker_stall_timeout > 0)
+                .then(|| Duration::from_secs(config.worker_stall_timeout)),
+            last_progress: now,
+            last_count: 0,
+            last_advance: now,
+            stalled: false,
+        }
+    }
+
+    pub fn report(&self, stats: &Statistics) -> ProgressReport {
+        ProgressReport::collect(stats, &self.range_size, self.coverage)
+    }
+
+    // Appelé à chaque tour de la boucle principale ; renvoie les notifications à émettre
+    pub fn tick(&mut self, stats: &Statistics, paused: bool) -> Vec<Notification> {
+        let mut notifications = Vec::new();
+        let now = Instant::now();
+
+        if let Some(interval) = self.progress_interval {
+            if now.duration_since(self.last_progress) >= interval {
+                self.last_progress = now;
+                notifications.push(Notification::Progress { report: self.report(stats) });
+            }
+        }
+
+        let count = stats.keys_checked.load(Ordering::Relaxed);
+        if paused {
+            // Une pause demandée n'est pas une panne
+            self.last_advance = now;
+        } else if count != self.last_count {
+            self.last_count = count;
+            self.last_advance = now;
+            if self.stalled {
+                self.stalled = false;
+                notifications.push(Notification::alert(format!(
+                    "✅ La recherche a repris ({} clés testées).", count
+                )));
+            }
+        } else if let Some(timeout) = self.heartbeat_timeout {
+            let idle = now.duration_since(self.last_advance);
+            if !self.stalled && idle >= timeout {
+                self.stalled = true;
+                notifications.push(Notification::alert(format!(
+                    "🛑 Aucune clé testée depuis {} : débit tombé à zéro ({} clés au total).",
+                    format_duration(idle.as_secs()), count
+                )));
+            }
+        }
+
+        if let Some(timeout) = self.worker_stall_timeout {
+            notifications.extend(Self::detect_stalled_workers(stats, timeout, now));
+        }
+
+        notifications
+    }
+
+    // Un worker est bloqué lorsque son propre compteur n'avance plus, même si les autres progressent
+    fn detect_stalled_workers(stats: &Statistics, timeout: Duration, now: Instant) -> Vec<Notification> {
+        let mut notifications = Vec::new();
+        for worker in stats.workers() {
+            if worker.is_finished() {
+                worker.stalled.store(false, Ordering::Relaxed);
+                continue;
+            }
+            let idle = worker.rates().idle(now);
+            let stalled = idle >= timeout;
+            if stalled == worker.is_stalled() {
+                continue;
+            }
+            worker.stalled.store(stalled, Ordering::Relaxed);
+            notifications.push(Notification::alert(if stalled {
+                format!("⚠️ Worker {} {} bloqué : aucune clé testée depuis {}.",
+                    worker.backend, worker.id, format_duration(idle.as_secs()))
+            } else {
+                format!("✅ Worker {} {} reparti.", worker.backend, worker.id)
+            }));
+        }
+        notifications
+    }
+}
+
+// Alerte immédiate lorsqu'un thread panique. Le profil release utilise panic = "abort" :
+// l'alerte est donc livrée (ou écrite dans l'outbox) avant que le processus ne s'arrête.
+pub fn install_panic_alert(notifications: Arc<NotificationDispatcher>) {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+        let name = std::thread::current().name().unwrap_or("inconnu").to_string();
+        let message = format!("💥 Le thread '{}' s'est arrêté sur une erreur : {}", name, info);
+        events::emit(Level::Error, Event::Alert { message: message.clone() });
+        notifications.send(Notification::alert(message));
+        notifications.flush(Duration::from_secs(10));
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_coverage_matches_closed_form() {
+        assert_eq!(expected_random_coverage(0.0, 1e6), 0.0);
+        assert!((expected_random_coverage(1e6, 1e6) - (1.0 - (-1.0f64).exp())).abs() < 1e-12);
+        // Plage de 2^70 clés : la couverture reste mesurable malgré n << N
+        let tiny = expected_random_coverage(1e9, 2f64.powi(70));
+        assert!((tiny / (1e9 / 2f64.powi(70)) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn keys_for_half_probability_is_n_ln2() {
+        let n = keys_for_probability(0.5, 1e12);
+        assert!((n - 1e12 * std::f64::consts::LN_2).abs() < 1.0);
+        assert!((expected_random_coverage(n, 1e12) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn sequential_report_uses_worker_positions() {
+        let stats = Statistics::new();
+        let a = stats.register_worker(Backend::Cpu, 0, &UBig::from(0u32), &UBig::from(499u32));
+        let b = stats.register_worker(Backend::Cpu, 1, &UBig::from(500u32), &UBig::from(999u32));
+        a.set_position(&UBig::from(250u32));
+        b.set_position(&UBig::from(1000u32));
+
+        let report = ProgressReport::collect(&stats, &UBig::from(1000u32), Coverage::Positions);
+        assert_eq!(report.remaining_keys.as_deref(), Some("0xfa"));
+        assert!((report.coverage - 0.75).abs() < 1e-12);
+        assert_eq!(report.success_probability, report.coverage);
+        assert_eq!(report.eta_50_secs, None);
+    }
+
+    #[test]
+    fn exhaustive_report_counts_checked_keys() {
+        let stats = Statistics::new();
+        stats.add_cpu_keys(192);
+        let report = ProgressReport::collect(&stats, &UBig::from(256u32), Coverage::Exhaustive);
+        assert_eq!(report.remaining_keys.as_deref(), Some("0x40"));
+        assert!((report.coverage - 0.75).abs() < 1e-12);
+        assert_eq!(report.eta_50_secs, None);
+    }
+
+    #[test]
+    fn stride_and_shuffle_modes_are_exhaustive() {
+        for mode in [SearchMode::Stride, SearchMode::Shuffle] {
+            let config = Config { mode, ..Config::default() };
+            assert_eq!(Coverage::for_config(&config), Coverage::Exhaustive);
+        }
+    }
+
+    #[test]
+    fn huge_range_is_not_truncated() {
+        let stats = Statistics::new();
+        stats.add_cpu_keys(1 << 40);
+        let range = UBig::from(1u8) << 100;
+        let report = ProgressReport::collect(&stats, &range, Coverage::Random);
+        assert_eq!(report.range_size, format!("0x1{}", "0".repeat(25)));
+        assert!((report.coverage - 2f64.powi(-60)).abs() < 1e-25);
+    }
+}
@@ -0,0 +1,907 @@
+// Notifications : un trait commun et plusieurs implémentations (Telegram, webhook, commande, fichier)
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::monitor::ProgressReport;
+use crate::results::FoundKey;
+use crate::Config;
+
+pub type NotifyError = Box<dyn std::error::Error + Send + Sync>;
+
+// Événements transmis aux notificateurs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Notification {
+    // Clé trouvée ; les champs de clé sont vides si les résultats sont chiffrés
+    KeyFound { record: FoundKey },
+    // Rapport périodique d'avancement
+    Progress { report: ProgressReport },
+    // Alerte de surveillance (débit tombé à zéro, worker arrêté, reprise...)
+    Alert { timestamp: String, message: String },
+}
+
+impl Notification {
+    pub fn alert(message: String) -> Self {
+        Notification::Alert { timestamp: chrono::Local::now().to_rfc3339(), message }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Notification::KeyFound { .. } => "key_found",
+            Notification::Progress { .. } => "progress",
+            Notification::Alert { .. } => "alert",
+        }
+    }
+
+    // Texte HTML (format Telegram)
+    pub fn to_html(&self) -> String {
+        match self {
+            Notification::KeyFound { record } => {
+                let mut text = format!(
+                    "<b>🎉 Adresse trouvée ({} {})</b>\n\n<b>Adresse:</b> <code>{}</code>",
+                    record.backend, record.worker_id, record.target
+                );
+                if record.private_key_hex.is_empty() {
+                    text.push_str("\n<b>Clé Privée:</b> 🔒 chiffrée dans le fichier de résultats");
+                } else {
+                    text.push_str(&format!(
+                        "\n<b>Clé Privée (Hex):</b> <code>{}</code>",
+                        record.private_key_hex
+                    ));
+                }
+                text
+            }
+            Notification::Progress { report } => format!(
+                "<b>📊 Avancement</b>\n\n{}",
+                report.lines().join("\n")
+            ),
+            Notification::Alert { message, .. } => format!("<b>⚠️ Alerte solveur</b>\n\n{}", message),
+        }
+    }
+
+    // Texte brut (fichier, console)
+    pub fn to_text(&self) -> String {
+        match self {
+            Notification::KeyFound { record } => {
+                let key = if record.private_key_hex.is_empty() { "🔒 chiffrée" } else { &record.private_key_hex };
+                format!(
+                    "[{}] 🎉 Adresse trouvée ({} {}) : {} - Clé (hex): {}",
+                    record.timestamp, record.backend, record.worker_id, record.target, key
+                )
+            }
+            Notification::Progress { report } => format!("[{}] 📊 {}", report.timestamp, report.lines().join(" | ")),
+            Notification::Alert { timestamp, message } => format!("[{}] ⚠️ {}", timestamp, message),
+        }
+    }
+}
+
+pub trait Notifier: Send + Sync {
+    fn name(&self) -> &str;
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError>;
+}
+
+pub struct TelegramNotifier {
+    api_base: String,
+    token: String,
+    chat_id: String,
+    client: reqwest::blocking::Client,
+}
+
+impl TelegramNotifier {
+    pub fn new(token: &str, chat_id: &str) -> Self {
+        Self::with_api_base("https://api.telegram.org", token, chat_id)
+    }
+
+    pub fn with_api_base(api_base: &str, token: &str, chat_id: &str) -> Self {
+        Self {
+            api_base: api_base.trim_end_matches('/').to_string(),
+            token: token.to_string(),
+            chat_id: chat_id.to_string(),
+            client: http_client(),
+        }
+    }
+}
+
+impl Notifier for TelegramNotifier {
+    fn name(&self) -> &str {
+        "telegram"
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let url = format!("{}/bot{}/sendMessage", self.api_base, self.token);
+        let text = notification.to_html();
+        let params = [
+            ("chat_id", self.chat_id.as_str()),
+            ("text", text.as_str()),
+            ("parse_mode", "HTML"),
+        ];
+
+        check_status(self.client.post(&url).form(&params).send()?)
+    }
+}
+
+// POST JSON de la notification vers une URL quelconque
+pub struct WebhookNotifier {
+    url: String,
+    client: reqwest::blocking::Client,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: &str) -> Self {
+        Self { url: url.to_string(), client: http_client() }
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        check_status(self.client.post(&self.url).json(notification).send()?)
+    }
+}
+
+fn http_client() -> reqwest::blocking::Client {
+    reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(15))
+        .build()
+        .unwrap_or_else(|_| reqwest::blocking::Client::new())
+}
+
+// Une réponse non 2xx est un échec de livraison (token invalide, limite de débit, serveur en panne...)
+fn check_status(response: reqwest::blocking::Response) -> Result<(), NotifyError> {
+    let status = response.status();
+    if status.is_success() {
+        Ok(())
+    } else {
+        let body = response.text().unwrap_or_default();
+        Err(format!("réponse HTTP {}: {}", status, body.chars().take(200).collect::<String>()).into())
+    }
+}
+
+// Durée maximale d'exécution de la commande de notification
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Exécution d'une commande locale : la notification JSON est passée sur l'entrée standard,
+// le type d'événement dans la variable SOLVER_EVENT
+pub struct CommandNotifier {
+    command: String,
+    timeout: Duration,
+}
+
+impl CommandNotifier {
+    pub fn new(command: &str) -> Self {
+        Self { command: command.to_string(), timeout: COMMAND_TIMEOUT }
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Notifier for CommandNotifier {
+    fn name(&self) -> &str {
+        "command"
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let mut command = if cfg!(target_os = "windows") {
+            let mut command = Command::new("cmd");
+            command.arg("/C");
+            command
+        } else {
+            let mut command = Command::new("sh");
+            command.arg("-c");
+            command
+        };
+
+        let mut child = command
+            .arg(&self.command)
+            .env("SOLVER_EVENT", notification.kind())
+            .stdin(Stdio::piped())
+            .spawn()?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(serde_json::to_string(notification)?.as_bytes())?;
+        }
+
+        // Une commande bloquée ne doit pas immobiliser le thread de livraison : elle est tuée à l'échéance
+        let deadline = Instant::now() + self.timeout;
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("la commande '{}' n'a pas terminé en {:?}", self.command, self.timeout).into());
+            }
+            thread::sleep(Duration::from_millis(20));
+        };
+        if !status.success() {
+            return Err(format!("la commande '{}' a échoué ({})", self.command, status).into());
+        }
+        Ok(())
+    }
+}
+
+// Écriture des notifications en JSON Lines dans un fichier, ou en texte sur la sortie standard ("-")
+pub struct FileNotifier {
+    path: String,
+}
+
+impl FileNotifier {
+    pub fn new(path: &str) -> Self {
+        Self { path: path.to_string() }
+    }
+}
+
+impl Notifier for FileNotifier {
+    fn name(&self) -> &str {
+        "file"
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        if self.path == "-" || self.path == "stdout" {
+            println!("{}", notification.to_text());
+            return Ok(());
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(notification)?)?;
+        Ok(())
+    }
+}
+
+// Construit la liste des notificateurs déclarés dans `notifiers=`
+pub fn build_notifiers(config: &Config) -> Vec<Box<dyn Notifier>> {
+    let mut notifiers: Vec<Box<dyn Notifier>> = Vec::new();
+
+    for name in &config.notifiers {
+        match name.as_str() {
+            "telegram" => match (&config.telegram_bot_token, &config.telegram_chat_id) {
+                (Some(token), Some(chat_id)) => notifiers.push(Box::new(TelegramNotifier::new(token, chat_id))),
+                _ => eprintln!("⚠️  Notificateur 'telegram' ignoré : telegram_bot_token et telegram_chat_id requis."),
+            },
+            "webhook" => match &config.webhook_url {
+                Some(url) => notifiers.push(Box::new(WebhookNotifier::new(url))),
+                None => eprintln!("⚠️  Notificateur 'webhook' ignoré : webhook_url requis."),
+            },
+            "command" => match &config.notify_command {
+                Some(command) => notifiers.push(Box::new(CommandNotifier::new(command))),
+                None => eprintln!("⚠️  Notificateur 'command' ignoré : notify_command requis."),
+            },
+            "file" => notifiers.push(Box::new(FileNotifier::new(&config.notify_file))),
+            other => eprintln!("⚠️  Notificateur inconnu ignoré : '{}'", other),
+        }
+    }
+
+    notifiers
+}
+
+// Paramètres de livraison des notifications
+#[derive(Debug, Clone)]
+pub struct DispatcherSettings {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub outbox_file: String,
+    // Délai laissé aux dernières livraisons lors de l'arrêt avant écriture dans l'outbox
+    pub shutdown_grace: Duration,
+}
+
+impl DispatcherSettings {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            max_retries: config.notify_max_retries,
+            initial_backoff: Duration::from_millis(config.notify_backoff_ms),
+            max_backoff: Duration::from_secs(300),
+            outbox_file: config.notify_outbox.clone(),
+            shutdown_grace: Duration::from_secs(10),
+        }
+    }
+}
+
+// Livraison en attente pour un notificateur donné (format d'une ligne de l'outbox)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Delivery {
+    notifier: String,
+    notification: Notification,
+    #[serde(skip)]
+    attempts: u32,
+    #[serde(skip, default = "Instant::now")]
+    next_attempt: Instant,
+    // Livraison relue depuis l'outbox : sa ligne reste dans le fichier de rejeu jusqu'à sa livraison ou sa persistance
+    #[serde(skip)]
+    replayed: bool,
+}
+
+// File de notifications traitée par un thread dédié : les workers ne bloquent jamais sur le réseau.
+// Chaque livraison est retentée avec un délai exponentiel ; celles qui échouent définitivement,
+// ou qui sont encore en attente à l'arrêt, sont conservées dans l'outbox et rejouées au démarrage suivant.
+pub struct NotificationDispatcher {
+    sender: Mutex<Option<Sender<Notification>>>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+    notifier_names: Vec<String>,
+    counters: std::sync::Arc<DispatchCounters>,
+    shutdown_grace: Duration,
+}
+
+#[derive(Debug, Default)]
+pub struct DispatchCounters {
+    pub delivered: AtomicU64,
+    pub failed_attempts: AtomicU64,
+    // Notifications écrites (et synchronisées) dans l'outbox
+    pub persisted: AtomicU64,
+    // Notifications perdues : ni livrées, ni écrites dans l'outbox
+    pub dropped: AtomicU64,
+    // Livraisons acceptées mais pas encore terminées (livrées ou écrites dans l'outbox)
+    in_flight: AtomicU64,
+}
+
+impl NotificationDispatcher {
+    pub fn start(notifiers: Vec<Box<dyn Notifier>>, settings: DispatcherSettings) -> Self {
+        let (sender, receiver) = mpsc::channel::<Notification>();
+        let notifier_names = notifiers.iter().map(|n| n.name().to_string()).collect();
+        let counters = std::sync::Arc::new(DispatchCounters::default());
+
+        let replayed = take_outbox(&settings.outbox_file);
+        if !replayed.is_empty() {
+            println!("📬 {} notification(s) en attente rejouée(s) depuis '{}'", replayed.len(), settings.outbox_file);
+        }
+
+        counters.in_flight.fetch_add(replayed.len() as u64, Ordering::SeqCst);
+        let thread_counters = counters.clone();
+        let shutdown_grace = settings.shutdown_grace;
+        let handle = thread::spawn(move || {
+            let replaying = replayed.len();
+            let mut worker = DispatchWorker { notifiers, settings, counters: thread_counters, pending: VecDeque::new(), replaying };
+            for delivery in replayed {
+                worker.queue(delivery);
+            }
+            worker.run(receiver);
+        });
+
+        Self {
+            sender: Mutex::new(Some(sender)),
+            handle: Mutex::new(Some(handle)),
+            notifier_names,
+            counters,
+            shutdown_grace,
+        }
+    }
+
+    pub fn notifier_names(&self) -> &[String] {
+        &self.notifier_names
+    }
+
+    pub fn counters(&self) -> &DispatchCounters {
+        &self.counters
+    }
+
+    // Ajoute une notification à la file sans attendre sa livraison
+    pub fn send(&self, notification: Notification) {
+        if let Some(sender) = self.sender.lock().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            self.counters.in_flight.fetch_add(self.notifier_names.len() as u64, Ordering::SeqCst);
+            if sender.send(notification).is_err() {
+                self.counters.in_flight.fetch_sub(self.notifier_names.len() as u64, Ordering::SeqCst);
+            }
+        }
+    }
+
+    // Attend que toutes les notifications envoyées soient livrées ou persistées, au plus `timeout`
+    pub fn flush(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        while self.counters.in_flight.load(Ordering::SeqCst) > 0 {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+        true
+    }
+
+    // Ferme la file et attend la fin des livraisons (bornée par `shutdown_grace`). Un notificateur bloqué
+    // au-delà du délai est abandonné : le thread de livraison n'est plus attendu.
+    pub fn shutdown(&self) {
+        self.sender.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(handle) = self.handle.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            // Marge laissée après le délai pour l'écriture de l'outbox
+            let deadline = Instant::now() + self.shutdown_grace + Duration::from_secs(1);
+            while !handle.is_finished() {
+                if Instant::now() >= deadline {
+                    eprintln!("⚠️  Notifications toujours en cours de livraison après {:?}, arrêt sans les attendre", self.shutdown_grace);
+                    return;
+                }
+                thread::sleep(Duration::from_millis(20));
+            }
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for NotificationDispatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+struct DispatchWorker {
+    notifiers: Vec<Box<dyn Notifier>>,
+    settings: DispatcherSettings,
+    counters: std::sync::Arc<DispatchCounters>,
+    pending: VecDeque<Delivery>,
+    // Livraisons rejouées encore en attente : le fichier de rejeu est supprimé quand il n'en reste plus
+    replaying: usize,
+}
+
+impl DispatchWorker {
+    fn queue(&mut self, delivery: Delivery) {
+        if self.notifiers.iter().any(|n| n.name() == delivery.notifier) {
+            self.pending.push_back(delivery);
+        } else {
+            // Notificateur retiré de la configuration : la notification reste dans l'outbox
+            self.persist(&delivery);
+            self.settle(&delivery);
+        }
+    }
+
+    // Livraison terminée (livrée ou persistée dans l'outbox)
+    fn settle(&mut self, delivery: &Delivery) {
+        self.counters.in_flight.fetch_sub(1, Ordering::SeqCst);
+        if delivery.replayed {
+            self.replaying -= 1;
+            if self.replaying == 0 {
+                remove_replay_file(&self.settings.outbox_file);
+            }
+        }
+    }
+
+    fn run(&mut self, receiver: mpsc::Receiver<Notification>) {
+        let mut shutdown_deadline = None;
+
+        loop {
+            let now = Instant::now();
+            let wait = self.pending.iter()
+                .map(|d| d.next_attempt.saturating_duration_since(now))
+                .min()
+                .unwrap_or(Duration::from_secs(3600));
+
+            if shutdown_deadline.is_none() {
+                match receiver.recv_timeout(wait) {
+                    Ok(notification) => {
+                        for notifier in &self.notifiers {
+                            self.pending.push_back(Delivery {
+                                notifier: notifier.name().to_string(),
+                                notification: notification.clone(),
+                                attempts: 0,
+                                next_attempt: Instant::now(),
+                                replayed: false,
+                            });
+                        }
+                    },
+                    Err(RecvTimeoutError::Timeout) => {},
+                    Err(RecvTimeoutError::Disconnected) => {
+                        shutdown_deadline = Some(Instant::now() + self.settings.shutdown_grace);
+                    },
+                }
+            } else if !wait.is_zero() {
+                thread::sleep(wait.min(Duration::from_millis(100)));
+            }
+
+            self.deliver_due();
+
+            if let Some(deadline) = shutdown_deadline {
+                if self.pending.is_empty() {
+                    return;
+                }
+                if Instant::now() >= deadline {
+                    for delivery in std::mem::take(&mut self.pending) {
+                        self.persist(&delivery);
+                        self.settle(&delivery);
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    fn deliver_due(&mut self) {
+        let now = Instant::now();
+        let (due, waiting): (VecDeque<_>, VecDeque<_>) =
+            std::mem::take(&mut self.pending).into_iter().partition(|d| d.next_attempt <= now);
+        self.pending = waiting;
+
+        for mut delivery in due {
+            let Some(notifier) = self.notifiers.iter().find(|n| n.name() == delivery.notifier) else {
+                continue;
+            };
+
+            match notifier.notify(&delivery.notification) {
+                Ok(()) => {
+                    self.counters.delivered.fetch_add(1, Ordering::Relaxed);
+                    self.settle(&delivery);
+                },
+                Err(e) => {
+                    self.counters.failed_attempts.fetch_add(1, Ordering::Relaxed);
+                    delivery.attempts += 1;
+                    if delivery.attempts > self.settings.max_retries {
+                        eprintln!("Notification '{}' abandonnée après {} tentatives ({}), conservée dans '{}'",
+                            delivery.notifier, delivery.attempts, e, self.settings.outbox_file);
+                        self.persist(&delivery);
+                        self.settle(&delivery);
+                    } else {
+                        let backoff = self.settings.initial_backoff
+                            .saturating_mul(1 << (delivery.attempts - 1).min(16))
+                            .min(self.settings.max_backoff);
+                        eprintln!("Erreur d'envoi de la notification '{}' (tentative {}): {} - nouvel essai dans {:?}",
+                            delivery.notifier, delivery.attempts, e, backoff);
+                        delivery.next_attempt = Instant::now() + backoff;
+                        self.pending.push_back(delivery);
+                    }
+                },
+            }
+        }
+    }
+
+    fn persist(&self, delivery: &Delivery) {
+        let line = match serde_json::to_string(delivery) {
+            Ok(line) => line,
+            Err(e) => {
+                eprintln!("Impossible de sérialiser la notification '{}': {}", delivery.notifier, e);
+                self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+        };
+        let written = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.settings.outbox_file)
+            .and_then(|mut file| {
+                writeln!(file, "{}", line)?;
+                file.sync_all()
+            });
+        match written {
+            Ok(()) => { self.counters.persisted.fetch_add(1, Ordering::Relaxed); },
+            Err(e) => {
+                eprintln!("Impossible d'écrire dans l'outbox '{}': {}", self.settings.outbox_file, e);
+                self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+            },
+        }
+    }
+}
+
+// Fichier des notifications en cours de rejeu : `<outbox>.replaying`
+fn replay_path(outbox: &str) -> String {
+    format!("{}.replaying", outbox)
+}
+
+fn remove_replay_file(outbox: &str) {
+    if let Err(e) = std::fs::remove_file(replay_path(outbox)) {
+        eprintln!("Impossible de supprimer le fichier de rejeu '{}': {}", replay_path(outbox), e);
+    }
+}
+
+// Déplace l'outbox vers le fichier de rejeu et renvoie les notifications à rejouer ; les entrées illisibles
+// sont ignorées. Le fichier de rejeu n'est supprimé qu'une fois chaque entrée livrée ou de nouveau persistée :
+// après un arrêt brutal pendant le rejeu, il est repris au démarrage suivant (une notification peut alors
+// être livrée deux fois, jamais perdue).
+fn take_outbox(path: &str) -> Vec<Delivery> {
+    let replay = replay_path(path);
+    if std::path::Path::new(path).exists() {
+        let moved = if std::path::Path::new(&replay).exists() {
+            // Rejeu précédent interrompu : l'outbox est ajoutée à la suite du fichier de rejeu
+            std::fs::read(path).and_then(|pending| {
+                let mut file = OpenOptions::new().append(true).open(&replay)?;
+                file.write_all(&pending)?;
+                file.sync_all()?;
+                std::fs::remove_file(path)
+            })
+        } else {
+            std::fs::rename(path, &replay)
+        };
+        if let Err(e) = moved {
+            eprintln!("Impossible de préparer le rejeu de l'outbox '{}': {}", path, e);
+            return Vec::new();
+        }
+    }
+
+    let Ok(file) = File::open(&replay) else {
+        return Vec::new();
+    };
+
+    let deliveries: Vec<Delivery> = BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str::<Delivery>(&line) {
+            Ok(delivery) => Some(Delivery { replayed: true, ..delivery }),
+            Err(e) => {
+                eprintln!("Entrée d'outbox ignorée ({}): {}", e, line);
+                None
+            }
+        })
+        .collect();
+
+    if deliveries.is_empty() {
+        remove_replay_file(path);
+    }
+
+    deliveries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::results::Backend;
+    use std::io::Read;
+    use std::net::TcpListener;
+    use std::sync::Arc;
+
+    // Serveur HTTP minimal : répond avec les codes de `statuses` dans l'ordre (le dernier est répété)
+    // et conserve le corps de chaque requête reçue
+    struct StandInServer {
+        url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl StandInServer {
+        fn start(statuses: Vec<u16>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let received = requests.clone();
+            thread::spawn(move || {
+                for (index, stream) in listener.incoming().enumerate() {
+                    let Ok(mut stream) = stream else { continue };
+                    let body = read_request_body(&mut stream);
+                    received.lock().unwrap().push(body);
+
+                    let status = statuses[index.min(statuses.len() - 1)];
+                    let response = format!("HTTP/1.1 {} STATUS\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok", status);
+                    let _ = stream.write_all(response.as_bytes());
+                }
+            });
+
+            Self { url, requests }
+        }
+
+        fn request_count(&self) -> usize {
+            self.requests.lock().unwrap().len()
+        }
+    }
+
+    fn read_request_body(stream: &mut std::net::TcpStream) -> String {
+        let mut data = Vec::new();
+        let mut buffer = [0u8; 4096];
+        loop {
+            let n = stream.read(&mut buffer).unwrap_or(0);
+            if n == 0 {
+                break;
+            }
+            data.extend_from_slice(&buffer[..n]);
+
+            let text = String::from_utf8_lossy(&data);
+            if let Some(header_end) = text.find("\r\n\r\n") {
+                let content_length = text[..header_end]
+                    .lines()
+                    .find_map(|line| line.to_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap_or(0)))
+                    .unwrap_or(0);
+                if data.len() >= header_end + 4 + content_length {
+                    return String::from_utf8_lossy(&data[header_end + 4..]).to_string();
+                }
+            }
+        }
+        String::new()
+    }
+
+    fn sample_notification() -> Notification {
+        Notification::KeyFound {
+            record: FoundKey {
+                timestamp: "2024-06-24T20:15:30+00:00".to_string(),
+                target: "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH".to_string(),
+                private_key_hex: "1".to_string(),
+                private_key_dec: "1".to_string(),
+                wif_compressed: "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn".to_string(),
+                wif_uncompressed: "5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsreAnchuDf".to_string(),
+                public_key: "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798".to_string(),
+                address_type: "p2pkh_compressed".to_string(),
+                backend: Backend::Cpu,
+                worker_id: 0,
+                mode: "sequential".to_string(),
+                range_start: "0x1".to_string(),
+                range_end: "0x1".to_string(),
+            },
+        }
+    }
+
+    fn settings(outbox_file: &str, max_retries: u32) -> DispatcherSettings {
+        DispatcherSettings {
+            max_retries,
+            initial_backoff: Duration::from_millis(20),
+            max_backoff: Duration::from_millis(100),
+            outbox_file: outbox_file.to_string(),
+            shutdown_grace: Duration::from_secs(5),
+        }
+    }
+
+    fn temp_outbox(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("solver_outbox_{}_{}.jsonl", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(replay_path(&path.to_string_lossy()));
+        path.to_string_lossy().to_string()
+    }
+
+    // Notificateur qui ne rend jamais la main : simule un processus tué pendant une livraison
+    struct HangingNotifier(Arc<AtomicU64>);
+
+    impl Notifier for HangingNotifier {
+        fn name(&self) -> &str {
+            "webhook"
+        }
+
+        fn notify(&self, _notification: &Notification) -> Result<(), NotifyError> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            loop {
+                thread::sleep(Duration::from_secs(3600));
+            }
+        }
+    }
+
+    #[test]
+    fn http_error_status_is_a_failure() {
+        let server = StandInServer::start(vec![500]);
+        let result = WebhookNotifier::new(&server.url).notify(&sample_notification());
+        assert!(result.is_err());
+        assert_eq!(server.request_count(), 1);
+    }
+
+    #[test]
+    fn telegram_posts_to_bot_endpoint_and_checks_status() {
+        let server = StandInServer::start(vec![401, 200]);
+        let notifier = TelegramNotifier::with_api_base(&server.url, "TOKEN", "42");
+
+        assert!(notifier.notify(&sample_notification()).is_err());
+        assert!(notifier.notify(&sample_notification()).is_ok());
+
+        let requests = server.requests.lock().unwrap();
+        assert!(requests[1].contains("chat_id=42"));
+    }
+
+    #[test]
+    fn failed_delivery_is_retried_with_backoff() {
+        let server = StandInServer::start(vec![503, 502, 200]);
+        let outbox = temp_outbox("retry");
+
+        let dispatcher = NotificationDispatcher::start(vec![Box::new(WebhookNotifier::new(&server.url))], settings(&outbox, 5));
+        dispatcher.send(sample_notification());
+        dispatcher.shutdown();
+
+        assert_eq!(server.request_count(), 3);
+        assert_eq!(dispatcher.counters().delivered.load(Ordering::Relaxed), 1);
+        assert_eq!(dispatcher.counters().failed_attempts.load(Ordering::Relaxed), 2);
+        assert!(!std::path::Path::new(&outbox).exists());
+
+        let body: serde_json::Value = serde_json::from_str(&server.requests.lock().unwrap()[2]).unwrap();
+        assert_eq!(body["event"], "key_found");
+        assert_eq!(body["record"]["target"], "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
+    }
+
+    #[test]
+    fn undelivered_notification_is_persisted_and_replayed() {
+        let outbox = temp_outbox("replay");
+
+        let down = StandInServer::start(vec![500]);
+        let dispatcher = NotificationDispatcher::start(vec![Box::new(WebhookNotifier::new(&down.url))], settings(&outbox, 1));
+        dispatcher.send(sample_notification());
+        dispatcher.shutdown();
+
+        assert_eq!(down.request_count(), 2);
+        assert_eq!(dispatcher.counters().persisted.load(Ordering::Relaxed), 1);
+        assert!(std::fs::read_to_string(&outbox).unwrap().contains("\"notifier\":\"webhook\""));
+
+        let up = StandInServer::start(vec![200]);
+        let dispatcher = NotificationDispatcher::start(vec![Box::new(WebhookNotifier::new(&up.url))], settings(&outbox, 1));
+        dispatcher.shutdown();
+
+        assert_eq!(up.request_count(), 1);
+        assert_eq!(dispatcher.counters().delivered.load(Ordering::Relaxed), 1);
+        assert!(!std::path::Path::new(&outbox).exists());
+    }
+
+    #[test]
+    fn outbox_survives_a_crash_during_replay() {
+        let outbox = temp_outbox("crash");
+        let down = StandInServer::start(vec![500]);
+        let dispatcher = NotificationDispatcher::start(vec![Box::new(WebhookNotifier::new(&down.url))], settings(&outbox, 0));
+        dispatcher.send(sample_notification());
+        dispatcher.send(sample_notification());
+        dispatcher.shutdown();
+        assert_eq!(dispatcher.counters().persisted.load(Ordering::Relaxed), 2);
+
+        // Rejeu bloqué sur la première livraison puis abandonné sans arrêt propre (processus tué)
+        let attempts = Arc::new(AtomicU64::new(0));
+        let hanging = NotificationDispatcher::start(vec![Box::new(HangingNotifier(attempts.clone()))], settings(&outbox, 0));
+        while attempts.load(Ordering::SeqCst) == 0 {
+            thread::sleep(Duration::from_millis(10));
+        }
+        std::mem::forget(hanging);
+        assert!(!std::path::Path::new(&outbox).exists());
+        let pending = std::fs::read_to_string(replay_path(&outbox)).unwrap();
+        assert_eq!(pending.lines().count(), 2);
+
+        // Le démarrage suivant reprend le rejeu interrompu, puis supprime le fichier de rejeu
+        let up = StandInServer::start(vec![200]);
+        let dispatcher = NotificationDispatcher::start(vec![Box::new(WebhookNotifier::new(&up.url))], settings(&outbox, 0));
+        dispatcher.shutdown();
+        assert_eq!(up.request_count(), 2);
+        assert!(!std::path::Path::new(&outbox).exists());
+        assert!(!std::path::Path::new(&replay_path(&outbox)).exists());
+    }
+
+    #[test]
+    fn replay_file_is_kept_until_failed_replays_are_persisted_again() {
+        let outbox = temp_outbox("replay_again");
+        let down = StandInServer::start(vec![500]);
+        let dispatcher = NotificationDispatcher::start(vec![Box::new(WebhookNotifier::new(&down.url))], settings(&outbox, 0));
+        dispatcher.send(sample_notification());
+        dispatcher.shutdown();
+
+        // Rejeu toujours en échec : l'entrée revient dans l'outbox et le fichier de rejeu disparaît
+        let dispatcher = NotificationDispatcher::start(vec![Box::new(WebhookNotifier::new(&down.url))], settings(&outbox, 0));
+        dispatcher.shutdown();
+        assert_eq!(down.request_count(), 2);
+        assert_eq!(std::fs::read_to_string(&outbox).unwrap().lines().count(), 1);
+        assert!(!std::path::Path::new(&replay_path(&outbox)).exists());
+        let _ = std::fs::remove_file(&outbox);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn hanging_command_is_killed_after_its_timeout() {
+        let notifier = CommandNotifier::new("sleep 30").with_timeout(Duration::from_millis(200));
+        let started = Instant::now();
+        assert!(notifier.notify(&sample_notification()).is_err());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn shutdown_does_not_wait_for_a_hanging_notifier() {
+        let outbox = temp_outbox("hanging_shutdown");
+        let attempts = Arc::new(AtomicU64::new(0));
+        let mut settings = settings(&outbox, 0);
+        settings.shutdown_grace = Duration::from_millis(100);
+        let dispatcher = NotificationDispatcher::start(vec![Box::new(HangingNotifier(attempts.clone()))], settings);
+        dispatcher.send(sample_notification());
+        while attempts.load(Ordering::SeqCst) == 0 {
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        let started = Instant::now();
+        dispatcher.shutdown();
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn unwritable_outbox_counts_dropped_notifications() {
+        let outbox = std::env::temp_dir().join(format!("solver_outbox_absent_{}", std::process::id())).join("outbox.jsonl");
+        let down = StandInServer::start(vec![500]);
+        let dispatcher = NotificationDispatcher::start(vec![Box::new(WebhookNotifier::new(&down.url))], settings(&outbox.to_string_lossy(), 0));
+        dispatcher.send(sample_notification());
+        dispatcher.shutdown();
+
+        assert_eq!(dispatcher.counters().persisted.load(Ordering::Relaxed), 0);
+        assert_eq!(dispatcher.counters().dropped.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,143 @@
+// Débits glissants : échantillons réguliers des compteurs pour mesurer la vitesse réelle
+// sur une fenêtre récente (et non la moyenne depuis le démarrage)
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+// Fenêtres publiées dans l'API et les métriques
+pub const RATE_WINDOWS: [(&str, Duration); 3] = [
+    ("10s", Duration::from_secs(10)),
+    ("1m", Duration::from_secs(60)),
+    ("15m", Duration::from_secs(900)),
+];
+
+// Fenêtre utilisée pour l'affichage courant, le pic et le minimum
+pub const SHORT_WINDOW: Duration = Duration::from_secs(10);
+
+#[derive(Debug)]
+pub struct RateTracker {
+    samples: VecDeque<(Instant, u64)>,
+    peak: f64,
+    min: Option<f64>,
+    last_count: u64,
+    last_advance: Instant,
+}
+
+impl RateTracker {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            samples: VecDeque::from([(now, 0)]),
+            peak: 0.0,
+            min: None,
+            last_count: 0,
+            last_advance: now,
+        }
+    }
+
+    // Enregistre la valeur du compteur. Le pic et le minimum ne sont suivis qu'une fois la fenêtre
+    // courte remplie, et le minimum ignore les périodes de pause.
+    pub fn record(&mut self, now: Instant, count: u64, paused: bool) {
+        self.samples.push_back((now, count));
+
+        // On garde un échantillon au-delà de la plus grande fenêtre pour couvrir toute sa durée
+        let longest = RATE_WINDOWS[RATE_WINDOWS.len() - 1].1;
+        while self.samples.len() > 2 && now.duration_since(self.samples[1].0) >= longest {
+            self.samples.pop_front();
+        }
+
+        if count != self.last_count || paused {
+            self.last_count = count;
+            self.last_advance = now;
+        }
+
+        if now.duration_since(self.samples[0].0) >= SHORT_WINDOW {
+            if let Some(rate) = self.rate(SHORT_WINDOW) {
+                self.peak = self.peak.max(rate);
+                if !paused {
+                    self.min = Some(self.min.map_or(rate, |min| min.min(rate)));
+                }
+            }
+        }
+    }
+
+    // Vitesse (clés/s) sur la fenêtre demandée ; None tant qu'un seul échantillon est disponible
+    pub fn rate(&self, window: Duration) -> Option<f64> {
+        let &(newest_time, newest_count) = self.samples.back()?;
+        let &(oldest_time, oldest_count) = self
+            .samples
+            .iter()
+            .find(|(time, _)| newest_time.duration_since(*time) <= window)?;
+
+        let elapsed = newest_time.duration_since(oldest_time).as_secs_f64();
+        (elapsed > 0.0).then(|| newest_count.saturating_sub(oldest_count) as f64 / elapsed)
+    }
+
+    pub fn windows(&self) -> Vec<(&'static str, f64)> {
+        RATE_WINDOWS
+            .iter()
+            .map(|(name, window)| (*name, self.rate(*window).unwrap_or(0.0)))
+            .collect()
+    }
+
+    pub fn peak(&self) -> f64 {
+        self.peak
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    // Durée depuis la dernière progression du compteur (hors pause)
+    pub fn idle(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.last_advance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker_with(counts: &[u64]) -> (RateTracker, Instant) {
+        let start = Instant::now();
+        let mut tracker = RateTracker::new(start);
+        for (i, count) in counts.iter().enumerate() {
+            tracker.record(start + Duration::from_secs(i as u64 + 1), *count, false);
+        }
+        (tracker, start + Duration::from_secs(counts.len() as u64))
+    }
+
+    #[test]
+    fn window_rate_reflects_recent_throughput() {
+        // 100 clés/s pendant 60 s, puis 10 clés/s pendant 20 s
+        let mut counts: Vec<u64> = (1..=60).map(|s| s * 100).collect();
+        counts.extend((1..=20).map(|s| 6000 + s * 10));
+        let (tracker, _) = tracker_with(&counts);
+
+        assert_eq!(tracker.rate(Duration::from_secs(10)), Some(10.0));
+        let minute = tracker.rate(Duration::from_secs(60)).unwrap();
+        assert!((minute - (4000.0 + 200.0) / 60.0).abs() < 1e-9);
+        assert_eq!(tracker.peak(), 100.0);
+        assert_eq!(tracker.min(), Some(10.0));
+    }
+
+    #[test]
+    fn no_rate_before_second_sample() {
+        let tracker = RateTracker::new(Instant::now());
+        assert_eq!(tracker.rate(SHORT_WINDOW), None);
+        assert_eq!(tracker.min(), None);
+    }
+
+    #[test]
+    fn idle_time_grows_when_count_stops() {
+        let (tracker, now) = tracker_with(&[10, 20, 30, 30, 30, 30]);
+        assert_eq!(tracker.idle(now), Duration::from_secs(3));
+        assert_eq!(tracker.rate(Duration::from_secs(3)), Some(0.0));
+    }
+
+    #[test]
+    fn old_samples_are_discarded() {
+        let counts: Vec<u64> = (1..=2000).collect();
+        let (tracker, _) = tracker_with(&counts);
+        assert!(tracker.samples.len() <= 902);
+        assert_eq!(tracker.rate(Duration::from_secs(900)), Some(1.0));
+    }
+}
@@ -0,0 +1,501 @@
+// Sous-commande `report` : rapport HTML ou Markdown construit à partir du journal d'événements
+// (historique des exécutions, vitesse dans le temps, couverture par plage, clés trouvées)
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use crate::events::{Event, EventRecord, SCHEMA_VERSION};
+use crate::monitor::format_duration;
+
+// Point de la courbe de vitesse
+#[derive(Debug, Clone)]
+pub struct RateSample {
+    pub elapsed_secs: u64,
+    pub rate: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct HitSummary {
+    pub ts: String,
+    pub target: String,
+    pub worker: String,
+}
+
+// Résumé d'une exécution (un `run_id`)
+#[derive(Debug, Clone, Default)]
+pub struct RunSummary {
+    pub run_id: String,
+    pub started: String,
+    pub ended: Option<String>,
+    pub version: String,
+    pub mode: String,
+    pub compute_mode: String,
+    pub range_start: String,
+    pub range_end: String,
+    pub threads: usize,
+    pub seed: Option<u64>,
+    pub samples: Vec<RateSample>,
+    pub elapsed_secs: u64,
+    pub keys_checked: u64,
+    pub average_rate: f64,
+    pub peak_rate: f64,
+    pub coverage: f64,
+    pub success_probability: f64,
+    pub hits: Vec<HitSummary>,
+    pub alerts: Vec<String>,
+    pub worker_exits: BTreeMap<String, usize>,
+}
+
+// Couverture cumulée de toutes les exécutions portant sur la même plage
+#[derive(Debug, Clone)]
+pub struct RangeSummary {
+    pub range_start: String,
+    pub range_end: String,
+    pub runs: usize,
+    pub keys_checked: u64,
+    pub sequential_coverage: f64,
+    // Probabilité d'avoir rencontré la clé sur l'ensemble des exécutions : 1 - Π(1 - p)
+    pub combined_probability: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct RunHistory {
+    pub runs: Vec<RunSummary>,
+    pub skipped_lines: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Html,
+    Markdown,
+}
+
+// Fichiers tournés d'abord (du plus ancien au plus récent), puis le fichier courant
+fn log_files(path: &str) -> Vec<String> {
+    let mut files: Vec<String> = (1..1000)
+        .map(|i| format!("{}.{}", path, i))
+        .take_while(|f| Path::new(f).exists())
+        .collect();
+    files.reverse();
+    if Path::new(path).exists() {
+        files.push(path.to_string());
+    }
+    files
+}
+
+pub fn load_history(path: &str) -> Result<RunHistory, String> {
+    let files = log_files(path);
+    if files.is_empty() {
+        return Err(format!("Journal d'événements introuvable: {}", path));
+    }
+
+    let mut history = RunHistory::default();
+    let mut index: BTreeMap<String, usize> = BTreeMap::new();
+
+    for file in files {
+        let reader = BufReader::new(File::open(&file).map_err(|e| format!("{}: {}", file, e))?);
+        for line in reader.lines().map_while(Result::ok) {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: EventRecord = match serde_json::from_str(&line) {
+                Ok(record) => record,
+                Err(_) => {
+                    history.skipped_lines += 1;
+                    continue;
+                }
+            };
+            if record.schema != SCHEMA_VERSION {
+                history.skipped_lines += 1;
+                continue;
+            }
+
+            let position = *index.entry(record.run_id.clone()).or_insert_with(|| {
+                history.runs.push(RunSummary {
+                    run_id: record.run_id.clone(),
+                    started: record.ts.clone(),
+                    ..RunSummary::default()
+                });
+                history.runs.len() - 1
+            });
+            apply_event(&mut history.runs[position], record);
+        }
+    }
+
+    Ok(history)
+}
+
+fn apply_event(run: &mut RunSummary, record: EventRecord) {
+    match record.event {
+        Event::Start { version, .. } => {
+            run.started = record.ts;
+            run.version = version;
+        },
+        Event::Config { mode, compute_mode, range_start, range_end, threads, seed, .. } => {
+            run.mode = mode;
+            run.compute_mode = compute_mode;
+            run.range_start = range_start;
+            run.range_end = range_end;
+            run.threads = threads;
+            run.seed = seed;
+        },
+        Event::Stats { report, .. } => {
+            run.elapsed_secs = report.elapsed_secs;
+            run.keys_checked = report.keys_checked;
+            run.coverage = report.coverage;
+            run.success_probability = report.success_probability;
+            run.peak_rate = run.peak_rate.max(report.rate);
+            if report.elapsed_secs > 0 {
+                run.average_rate = report.keys_checked as f64 / report.elapsed_secs as f64;
+            }
+            run.samples.push(RateSample {
+                elapsed_secs: report.elapsed_secs,
+                rate: report.rate,
+            });
+        },
+        Event::Hit { target, backend, worker, .. } => {
+            run.hits.push(HitSummary { ts: record.ts, target, worker: format!("{} {}", backend, worker) });
+        },
+        Event::WorkerExit { reason, .. } => {
+            *run.worker_exits.entry(reason).or_default() += 1;
+        },
+        Event::Alert { message } => run.alerts.push(message),
+        Event::End { elapsed_secs, keys_checked, average_rate, .. } => {
+            run.ended = Some(record.ts);
+            run.elapsed_secs = elapsed_secs;
+            run.keys_checked = keys_checked;
+            run.average_rate = average_rate;
+        },
+        Event::Checkpoint { .. } => {},
+    }
+}
+
+impl RunHistory {
+    pub fn ranges(&self) -> Vec<RangeSummary> {
+        let mut ranges: BTreeMap<(String, String), RangeSummary> = BTreeMap::new();
+        for run in self.runs.iter().filter(|run| !run.range_start.is_empty()) {
+            let range = ranges
+                .entry((run.range_start.clone(), run.range_end.clone()))
+                .or_insert_with(|| RangeSummary {
+                    range_start: run.range_start.clone(),
+                    range_end: run.range_end.clone(),
+                    runs: 0,
+                    keys_checked: 0,
+                    sequential_coverage: 0.0,
+                    combined_probability: 0.0,
+                });
+            range.runs += 1;
+            range.keys_checked += run.keys_checked;
+            if run.mode == "sequential" {
+                // Les points de contrôle font reprendre la plage : la couverture ne se cumule pas
+                range.sequential_coverage = range.sequential_coverage.max(run.coverage);
+            } else {
+                // Exécutions aléatoires indépendantes
+                range.combined_probability = 1.0 - (1.0 - range.combined_probability) * (1.0 - run.success_probability);
+            }
+        }
+        ranges
+            .into_values()
+            .map(|range| RangeSummary {
+                combined_probability: 1.0 - (1.0 - range.combined_probability) * (1.0 - range.sequential_coverage),
+                ..range
+            })
+            .collect()
+    }
+
+    pub fn total_hits(&self) -> usize {
+        self.runs.iter().map(|run| run.hits.len()).sum()
+    }
+}
+
+fn format_rate(rate: f64) -> String {
+    if rate >= 1_000_000.0 {
+        format!("{:.2} Mk/s", rate / 1_000_000.0)
+    } else {
+        format!("{:.2} k/s", rate / 1000.0)
+    }
+}
+
+fn status(run: &RunSummary) -> String {
+    match &run.ended {
+        Some(_) if run.worker_exits.contains_key("panic") => "💥 erreur".to_string(),
+        Some(_) => "✅ terminée".to_string(),
+        None => "⏳ en cours / interrompue".to_string(),
+    }
+}
+
+// Courbe compacte pour le Markdown (au plus 60 points)
+fn sparkline(samples: &[RateSample]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let step = samples.len().div_ceil(60).max(1);
+    let points: Vec<f64> = samples.chunks(step).map(|c| c.iter().map(|s| s.rate).sum::<f64>() / c.len() as f64).collect();
+    let max = points.iter().cloned().fold(0.0, f64::max);
+    points
+        .iter()
+        .map(|rate| if max > 0.0 { BARS[((rate / max) * 7.0).round() as usize] } else { BARS[0] })
+        .collect()
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Graphique SVG de la vitesse en fonction du temps écoulé
+fn rate_chart_svg(samples: &[RateSample]) -> String {
+    const WIDTH: f64 = 640.0;
+    const HEIGHT: f64 = 160.0;
+    let max_time = samples.iter().map(|s| s.elapsed_secs).max().unwrap_or(0).max(1) as f64;
+    let max_rate = samples.iter().map(|s| s.rate).fold(0.0, f64::max).max(1.0);
+    let points: Vec<String> = samples
+        .iter()
+        .map(|s| format!("{:.1},{:.1}", s.elapsed_secs as f64 / max_time * WIDTH, HEIGHT - s.rate / max_rate * HEIGHT))
+        .collect();
+
+    format!(
+        "<svg viewBox=\"-50 -10 {w} {h}\" width=\"{w}\" height=\"{h}\" class=\"chart\">\
+         <line x1=\"0\" y1=\"{hh}\" x2=\"{ww}\" y2=\"{hh}\" stroke=\"#999\"/>\
+         <line x1=\"0\" y1=\"0\" x2=\"0\" y2=\"{hh}\" stroke=\"#999\"/>\
+         <text x=\"-5\" y=\"8\" text-anchor=\"end\" font-size=\"10\">{max}</text>\
+         <text x=\"{ww}\" y=\"{label_y}\" text-anchor=\"end\" font-size=\"10\">{time}</text>\
+         <polyline fill=\"none\" stroke=\"#e67e22\" stroke-width=\"2\" points=\"{points}\"/></svg>",
+        w = WIDTH + 60.0,
+        h = HEIGHT + 30.0,
+        ww = WIDTH,
+        hh = HEIGHT,
+        label_y = HEIGHT + 14.0,
+        max = format_rate(max_rate),
+        time = format_duration(max_time as u64),
+        points = points.join(" "),
+    )
+}
+
+// Mode de recherche et de calcul, avec la graine permettant de rejouer l'exécution
+fn mode_label(run: &RunSummary) -> String {
+    match run.seed {
+        Some(seed) => format!("{} / {} (graine {})", run.mode, run.compute_mode, seed),
+        None => format!("{} / {}", run.mode, run.compute_mode),
+    }
+}
+
+pub fn render_markdown(history: &RunHistory) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# Bitcoin Puzzle Solver - Rapport d'exécution\n");
+    let _ = writeln!(out, "Généré le {} à partir de {} exécution(s), {} clé(s) trouvée(s).\n",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), history.runs.len(), history.total_hits());
+
+    let _ = writeln!(out, "## Historique des exécutions\n");
+    let _ = writeln!(out, "| Exécution | Début | Mode | Plage | Durée | Clés testées | Vitesse moyenne | Pic | Couverture | P(succès) | État |");
+    let _ = writeln!(out, "|-----------|-------|------|-------|-------|--------------|-----------------|-----|------------|-----------|------|");
+    for run in &history.runs {
+        let _ = writeln!(out, "| `{}` | {} | {} | {} → {} | {} | {} | {} | {} | {:.6}% | {:.6}% | {} |",
+            run.run_id, run.started, mode_label(run), run.range_start, run.range_end,
+            format_duration(run.elapsed_secs), run.keys_checked, format_rate(run.average_rate),
+            format_rate(run.peak_rate), run.coverage * 100.0, run.success_probability * 100.0, status(run));
+    }
+
+    let _ = writeln!(out, "\n## Vitesse dans le temps\n");
+    for run in history.runs.iter().filter(|run| !run.samples.is_empty()) {
+        let _ = writeln!(out, "- `{}` ({} → pic {}) : `{}`", run.run_id,
+            format_rate(run.average_rate), format_rate(run.peak_rate), sparkline(&run.samples));
+    }
+
+    let _ = writeln!(out, "\n## Couverture par plage\n");
+    let _ = writeln!(out, "| Plage | Exécutions | Clés testées | Couverture séquentielle | Probabilité cumulée |");
+    let _ = writeln!(out, "|-------|------------|--------------|-------------------------|---------------------|");
+    for range in history.ranges() {
+        let _ = writeln!(out, "| {} → {} | {} | {} | {:.6}% | {:.6}% |", range.range_start, range.range_end,
+            range.runs, range.keys_checked, range.sequential_coverage * 100.0, range.combined_probability * 100.0);
+    }
+
+    let _ = writeln!(out, "\n## Clés trouvées\n");
+    if history.total_hits() == 0 {
+        let _ = writeln!(out, "Aucune clé trouvée.");
+    } else {
+        let _ = writeln!(out, "| Date | Exécution | Adresse | Worker |");
+        let _ = writeln!(out, "|------|-----------|---------|--------|");
+        for run in &history.runs {
+            for hit in &run.hits {
+                let _ = writeln!(out, "| {} | `{}` | `{}` | {} |", hit.ts, run.run_id, hit.target, hit.worker);
+            }
+        }
+        let _ = writeln!(out, "\nLes clés privées ne figurent que dans le fichier de résultats (found.jsonl).");
+    }
+
+    let alerts: Vec<_> = history.runs.iter().flat_map(|run| run.alerts.iter().map(move |a| (run, a))).collect();
+    if !alerts.is_empty() {
+        let _ = writeln!(out, "\n## Alertes\n");
+        for (run, alert) in alerts {
+            let _ = writeln!(out, "- `{}` : {}", run.run_id, alert);
+        }
+    }
+    if history.skipped_lines > 0 {
+        let _ = writeln!(out, "\n_{} ligne(s) illisible(s) ou d'un autre schéma ignorée(s)._", history.skipped_lines);
+    }
+    out
+}
+
+pub fn render_html(history: &RunHistory) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+        <title>Bitcoin Puzzle Solver - Rapport d'exécution</title>\n<style>\n\
+        body {{ font-family: Arial, sans-serif; margin: 20px; }}\n\
+        .header {{ background: linear-gradient(45deg, #f39c12, #e67e22); color: white; padding: 20px; border-radius: 10px; }}\n\
+        .section {{ margin: 20px 0; padding: 15px; border-left: 4px solid #3498db; background: #f8f9fa; }}\n\
+        table {{ border-collapse: collapse; }} td, th {{ border: 1px solid #ddd; padding: 4px 8px; font-size: 13px; }}\n\
+        .found-key {{ background: #d4edda; }}\n.chart {{ background: white; margin: 8px 0; }}\n\
+        </style>\n</head>\n<body>");
+    let _ = writeln!(out, "<div class=\"header\"><h1>🚀 Bitcoin Puzzle Solver - Rapport d'exécution</h1>\
+        <p>Généré le {} — {} exécution(s), {} clé(s) trouvée(s)</p></div>",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), history.runs.len(), history.total_hits());
+
+    let _ = writeln!(out, "<div class=\"section\"><h2>📊 Historique des exécutions</h2>\n<table>\
+        <tr><th>Exécution</th><th>Début</th><th>Mode</th><th>Plage</th><th>Durée</th><th>Clés testées</th>\
+        <th>Vitesse moyenne</th><th>Pic</th><th>Couverture</th><th>P(succès)</th><th>État</th></tr>");
+    for run in &history.runs {
+        let _ = writeln!(out, "<tr><td>{}</td><td>{}</td><td>{}</td><td>{} → {}</td><td>{}</td><td>{}</td>\
+            <td>{}</td><td>{}</td><td>{:.6}%</td><td>{:.6}%</td><td>{}</td></tr>",
+            html_escape(&run.run_id), html_escape(&run.started), html_escape(&mode_label(run)),
+            html_escape(&run.range_start), html_escape(&run.range_end), format_duration(run.elapsed_secs),
+            run.keys_checked, format_rate(run.average_rate), format_rate(run.peak_rate),
+            run.coverage * 100.0, run.success_probability * 100.0, status(run));
+    }
+    let _ = writeln!(out, "</table></div>");
+
+    let _ = writeln!(out, "<div class=\"section\"><h2>⚡ Vitesse dans le temps</h2>");
+    for run in history.runs.iter().filter(|run| !run.samples.is_empty()) {
+        let _ = writeln!(out, "<h3>{}</h3>\n{}", html_escape(&run.run_id), rate_chart_svg(&run.samples));
+    }
+    let _ = writeln!(out, "</div>");
+
+    let _ = writeln!(out, "<div class=\"section\"><h2>🎯 Couverture par plage</h2>\n<table>\
+        <tr><th>Plage</th><th>Exécutions</th><th>Clés testées</th><th>Couverture séquentielle</th><th>Probabilité cumulée</th></tr>");
+    for range in history.ranges() {
+        let _ = writeln!(out, "<tr><td>{} → {}</td><td>{}</td><td>{}</td><td>{:.6}%</td><td>{:.6}%</td></tr>",
+            html_escape(&range.range_start), html_escape(&range.range_end), range.runs, range.keys_checked,
+            range.sequential_coverage * 100.0, range.combined_probability * 100.0);
+    }
+    let _ = writeln!(out, "</table></div>");
+
+    let _ = writeln!(out, "<div class=\"section\"><h2>🎉 Clés trouvées</h2>");
+    if history.total_hits() == 0 {
+        let _ = writeln!(out, "<p>Aucune clé trouvée.</p>");
+    } else {
+        let _ = writeln!(out, "<table><tr><th>Date</th><th>Exécution</th><th>Adresse</th><th>Worker</th></tr>");
+        for run in &history.runs {
+            for hit in &run.hits {
+                let _ = writeln!(out, "<tr class=\"found-key\"><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    html_escape(&hit.ts), html_escape(&run.run_id), html_escape(&hit.target), html_escape(&hit.worker));
+            }
+        }
+        let _ = writeln!(out, "</table><p>Les clés privées ne figurent que dans le fichier de résultats (found.jsonl).</p>");
+    }
+    let _ = writeln!(out, "</div>");
+
+    let alerts: Vec<_> = history.runs.iter().flat_map(|run| run.alerts.iter().map(move |a| (run, a))).collect();
+    if !alerts.is_empty() {
+        let _ = writeln!(out, "<div class=\"section\"><h2>⚠️ Alertes</h2><ul>");
+        for (run, alert) in alerts {
+            let _ = writeln!(out, "<li><code>{}</code> : {}</li>", html_escape(&run.run_id), html_escape(alert));
+        }
+        let _ = writeln!(out, "</ul></div>");
+    }
+    let _ = writeln!(out, "</body>\n</html>");
+    out
+}
+
+// Commande `report [--log <journal>] [--output <fichier>] [--format html|markdown]`
+pub fn run_report(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut log = "solver_events.jsonl".to_string();
+    let mut output = None;
+    let mut format = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--log" | "-l" => log = iter.next().ok_or("--log attend un fichier")?.clone(),
+            "--output" | "-o" => output = Some(iter.next().ok_or("--output attend un fichier")?.clone()),
+            "--format" | "-f" => {
+                format = Some(match iter.next().map(String::as_str) {
+                    Some("html") => ReportFormat::Html,
+                    Some("markdown" | "md") => ReportFormat::Markdown,
+                    _ => return Err("--format attend 'html' ou 'markdown'".into()),
+                })
+            },
+            other => return Err(format!("Argument inconnu: {}", other).into()),
+        }
+    }
+
+    let format = format.unwrap_or_else(|| match &output {
+        Some(path) if path.ends_with(".md") => ReportFormat::Markdown,
+        _ => ReportFormat::Html,
+    });
+    let output = output.unwrap_or_else(|| match format {
+        ReportFormat::Html => "report.html".to_string(),
+        ReportFormat::Markdown => "report.md".to_string(),
+    });
+
+    let history = load_history(&log)?;
+    let content = match format {
+        ReportFormat::Html => render_html(&history),
+        ReportFormat::Markdown => render_markdown(&history),
+    };
+    std::fs::write(&output, content)?;
+
+    println!("📄 Rapport généré: {} ({} exécution(s), {} clé(s) trouvée(s))",
+        output, history.runs.len(), history.total_hits());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOG: &str = r#"{"schema":1,"ts":"2025-01-15T10:00:00.000+01:00","level":"info","run_id":"a","event":"start","version":"2.0.0","pid":1}
+{"schema":1,"ts":"2025-01-15T10:00:00.001+01:00","level":"info","run_id":"a","event":"config","mode":"random","compute_mode":"cpu","range_start":"0x10","range_end":"0x1f","range_size":"0x10","threads":2,"targets":1}
+{"schema":1,"ts":"2025-01-15T10:01:00.000+01:00","level":"info","run_id":"a","event":"stats","timestamp":"x","elapsed_secs":60,"keys_checked":600,"rate":10.0,"cpu_rate":10.0,"gpu_rate":0.0,"range_size":"0x10","coverage":0.5,"success_probability":0.5,"remaining_keys":null,"eta_secs":null,"eta_50_secs":null,"found":0,"workers":[]}
+{"schema":1,"ts":"2025-01-15T10:02:00.000+01:00","level":"info","run_id":"a","event":"hit","target":"1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH","backend":"cpu","worker":1,"address_type":"p2pkh_compressed"}
+{"schema":1,"ts":"2025-01-15T10:02:00.001+01:00","level":"info","run_id":"a","event":"end","elapsed_secs":120,"keys_checked":1200,"average_rate":10.0,"found":1}
+not json
+{"schema":1,"ts":"2025-01-16T10:00:00.000+01:00","level":"info","run_id":"b","event":"config","mode":"random","compute_mode":"cpu","range_start":"0x10","range_end":"0x1f","range_size":"0x10","threads":2,"targets":1}
+{"schema":1,"ts":"2025-01-16T10:01:00.000+01:00","level":"info","run_id":"b","event":"stats","timestamp":"x","elapsed_secs":60,"keys_checked":300,"rate":5.0,"cpu_rate":5.0,"gpu_rate":0.0,"range_size":"0x10","coverage":0.5,"success_probability":0.5,"remaining_keys":null,"eta_secs":null,"eta_50_secs":null,"found":0,"workers":[]}
+"#;
+
+    // Journal propre à chaque test (les tests s'exécutent en parallèle)
+    fn history(name: &str) -> RunHistory {
+        let path = std::env::temp_dir().join(format!("solver_report_test_{}_{}.jsonl", name, std::process::id()));
+        std::fs::write(&path, LOG).unwrap();
+        let history = load_history(path.to_str().unwrap()).unwrap();
+        let _ = std::fs::remove_file(&path);
+        history
+    }
+
+    #[test]
+    fn runs_are_grouped_and_ranges_combined() {
+        let history = history("grouped");
+        assert_eq!(history.runs.len(), 2);
+        assert_eq!(history.skipped_lines, 1);
+        assert_eq!(history.runs[0].keys_checked, 1200);
+        assert_eq!(history.runs[0].hits.len(), 1);
+        assert!(history.runs[1].ended.is_none());
+
+        let ranges = history.ranges();
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].runs, 2);
+        assert!((ranges[0].combined_probability - 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn both_formats_list_runs_and_hits() {
+        let history = history("formats");
+        let markdown = render_markdown(&history);
+        assert!(markdown.contains("| `a` |"));
+        assert!(markdown.contains("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"));
+        assert!(markdown.contains("75.000000%"));
+
+        let html = render_html(&history);
+        assert!(html.contains("<polyline"));
+        assert!(html.contains("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"));
+    }
+}
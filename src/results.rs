@@ -0,0 +1,402 @@
+// Enregistrement des clés trouvées au format JSON Lines (une ligne JSON par découverte)
+use bitcoin::secp256k1::{All, Secp256k1, SecretKey};
+use ibig::UBig;
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::encryption::ResultEncryption;
+use crate::events::{self, Event, Level};
+use crate::notify::{Notification, NotificationDispatcher};
+use crate::{generate_address_variants, Statistics};
+
+// Type de worker ayant trouvé la clé
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    Cpu,
+    Gpu,
+}
+
+impl std::fmt::Display for Backend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Backend::Cpu => write!(f, "CPU"),
+            Backend::Gpu => write!(f, "GPU"),
+        }
+    }
+}
+
+// Enregistrement complet d'une clé trouvée (une ligne du fichier de résultats)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FoundKey {
+    pub timestamp: String,
+    pub target: String,
+    pub private_key_hex: String,
+    pub private_key_dec: String,
+    pub wif_compressed: String,
+    pub wif_uncompressed: String,
+    pub public_key: String,
+    pub address_type: String,
+    pub backend: Backend,
+    pub worker_id: usize,
+    pub mode: String,
+    pub range_start: String,
+    pub range_end: String,
+}
+
+impl FoundKey {
+    // Construit l'enregistrement à partir de la clé et de l'adresse qui a correspondu.
+    // Les deux variantes (compressée / non compressée) sont recalculées pour retrouver
+    // la clé publique et le type d'adresse exacts.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_match(
+        secp: &Secp256k1<All>,
+        secret_key: &SecretKey,
+        key_val: &UBig,
+        target: &str,
+        backend: Backend,
+        worker_id: usize,
+        mode: &str,
+        range: (&UBig, &UBig),
+    ) -> Self {
+        let variants = generate_address_variants(secp, secret_key);
+        let (matched_key, _) = variants
+            .iter()
+            .find(|(key, address)| address.to_string() == target || key.public_key(secp).to_string() == target)
+            .unwrap_or(&variants[0]);
+
+        let (wif_compressed, wif_uncompressed) = {
+            let mut compressed = *matched_key;
+            compressed.compressed = true;
+            let mut uncompressed = *matched_key;
+            uncompressed.compressed = false;
+            (compressed.to_wif(), uncompressed.to_wif())
+        };
+
+        let address_type = if matched_key.compressed {
+            "p2pkh_compressed"
+        } else {
+            "p2pkh_uncompressed"
+        };
+
+        Self {
+            timestamp: chrono::Local::now().to_rfc3339(),
+            target: target.to_string(),
+            private_key_hex: format!("{:x}", key_val),
+            private_key_dec: key_val.to_string(),
+            wif_compressed,
+            wif_uncompressed,
+            public_key: matched_key.public_key(secp).to_string(),
+            address_type: address_type.to_string(),
+            backend,
+            worker_id,
+            mode: mode.to_string(),
+            range_start: format!("0x{:x}", range.0),
+            range_end: format!("0x{:x}", range.1),
+        }
+    }
+
+    // Copie sans aucune donnée permettant de dépenser les fonds
+    pub fn redacted(&self) -> Self {
+        Self {
+            private_key_hex: String::new(),
+            private_key_dec: String::new(),
+            wif_compressed: String::new(),
+            wif_uncompressed: String::new(),
+            ..self.clone()
+        }
+    }
+
+    // Ligne lisible pour le résumé optionnel (found.txt)
+    pub fn summary_line(&self) -> String {
+        format!(
+            "[{}] [{} {}] Trouvé! Clé (hex): {}, Adresse: {}, WIF: {}",
+            self.timestamp,
+            self.backend,
+            self.worker_id,
+            self.private_key_hex,
+            self.target,
+            if self.address_type == "p2pkh_compressed" { &self.wif_compressed } else { &self.wif_uncompressed },
+        )
+    }
+}
+
+// Enveloppe écrite à la place de l'enregistrement lorsque le chiffrement est activé :
+// seules les métadonnées non sensibles restent lisibles
+#[derive(Debug, Serialize)]
+struct EncryptedRecord<'a> {
+    timestamp: &'a str,
+    target: &'a str,
+    backend: Backend,
+    worker_id: usize,
+    encrypted: String,
+}
+
+// Fichier de secours (répertoire courant) si le fichier de résultats ne peut pas être écrit
+pub const RESCUE_FILE: &str = "found_rescue.jsonl";
+// Tentatives d'écriture du fichier de résultats avant de passer au fichier de secours
+const WRITE_ATTEMPTS: u32 = 3;
+
+// Écriture des résultats : une ligne JSON par clé, écrite d'un seul bloc puis synchronisée sur disque
+pub struct ResultWriter {
+    results_file: String,
+    summary_file: Option<String>,
+    rescue_file: String,
+    encryption: Option<ResultEncryption>,
+    lock: Mutex<()>,
+}
+
+impl ResultWriter {
+    pub fn new(results_file: &str, summary_file: Option<&str>, encryption: Option<ResultEncryption>) -> Self {
+        Self {
+            results_file: results_file.to_string(),
+            summary_file: summary_file.map(str::to_string),
+            rescue_file: RESCUE_FILE.to_string(),
+            encryption,
+            lock: Mutex::new(()),
+        }
+    }
+
+    pub fn with_rescue_file(mut self, rescue_file: &str) -> Self {
+        self.rescue_file = rescue_file.to_string();
+        self
+    }
+
+    // Vrai si la clé privée ne doit jamais apparaître en clair (fichiers, console, notifications)
+    pub fn is_encrypted(&self) -> bool {
+        self.encryption.is_some()
+    }
+
+    // Clé (hex, décimal) telle qu'elle peut être affichée ou notifiée
+    pub fn visible_key<'a>(&self, record: &'a FoundKey) -> (&'a str, &'a str) {
+        if self.is_encrypted() {
+            ("🔒 chiffrée", "🔒 chiffrée")
+        } else {
+            (&record.private_key_hex, &record.private_key_dec)
+        }
+    }
+
+    // Ligne du fichier de résultats : l'enregistrement, ou son enveloppe chiffrée
+    fn line(&self, record: &FoundKey) -> std::io::Result<String> {
+        let mut line = match &self.encryption {
+            Some(encryption) => {
+                let plaintext = serde_json::to_vec(record)?;
+                let encrypted = encryption
+                    .encrypt(&plaintext)
+                    .map_err(|e| std::io::Error::other(e.to_string()))?;
+                serde_json::to_string(&EncryptedRecord {
+                    timestamp: &record.timestamp,
+                    target: &record.target,
+                    backend: record.backend,
+                    worker_id: record.worker_id,
+                    encrypted,
+                })?
+            }
+            None => serde_json::to_string(record)?,
+        };
+        line.push('\n');
+        Ok(line)
+    }
+
+    pub fn write(&self, record: &FoundKey) -> std::io::Result<()> {
+        let line = self.line(record)?;
+        let _lock = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        append_synced(&self.results_file, &line)?;
+
+        if let Some(summary_file) = &self.summary_file {
+            let summary = if self.is_encrypted() {
+                format!(
+                    "[{}] [{} {}] Trouvé! Adresse: {} (clé chiffrée dans {})",
+                    record.timestamp, record.backend, record.worker_id, record.target, self.results_file
+                )
+            } else {
+                record.summary_line()
+            };
+            append_synced(summary_file, &format!("{}\n", summary))?;
+        }
+
+        Ok(())
+    }
+
+    // Écrit le résultat malgré les erreurs : nouvelles tentatives sur le fichier de résultats, puis fichier
+    // de secours. La ligne (chiffrée si le chiffrement est actif) est aussi recopiée sur la sortie d'erreur
+    // si aucun fichier n'a pu être écrit. Renvoie le fichier qui contient le résultat.
+    pub fn write_or_rescue(&self, record: &FoundKey) -> Option<String> {
+        let mut error = None;
+        for attempt in 0..WRITE_ATTEMPTS {
+            if attempt > 0 {
+                std::thread::sleep(std::time::Duration::from_millis(100 << attempt));
+            }
+            match self.write(record) {
+                Ok(()) => return Some(self.results_file.clone()),
+                Err(e) => error = Some(e),
+            }
+        }
+        eprintln!("⚠️  [{} {}] Écriture impossible dans {} ({}), résultat conservé dans {}",
+            record.backend, record.worker_id, self.results_file, error.map_or_else(String::new, |e| e.to_string()), self.rescue_file);
+
+        let line = match self.line(record) {
+            Ok(line) => line,
+            Err(e) => {
+                eprintln!("⚠️  Résultat impossible à sérialiser ({}) : {}", e, record.target);
+                return None;
+            },
+        };
+        let _lock = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        match append_synced(&self.rescue_file, &line) {
+            Ok(()) => Some(self.rescue_file.clone()),
+            Err(e) => {
+                eprintln!("⚠️  Fichier de secours {} inutilisable ({}). Résultat à conserver :\n{}", self.rescue_file, e, line.trim_end());
+                None
+            },
+        }
+    }
+}
+
+// Ajout en fin de fichier en un seul appel d'écriture (O_APPEND), suivi d'un fsync
+fn append_synced(path: &str, content: &str) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()
+}
+
+// Point d'entrée unique pour chaque découverte, quel que soit le worker :
+// statistiques, affichage, écriture du résultat puis notifications
+pub struct ResultHandler {
+    writer: ResultWriter,
+    notifications: Arc<NotificationDispatcher>,
+    stats: Arc<Statistics>,
+    found: Arc<AtomicBool>,
+}
+
+impl ResultHandler {
+    pub fn new(writer: ResultWriter, notifications: Arc<NotificationDispatcher>, stats: Arc<Statistics>, found: Arc<AtomicBool>) -> Self {
+        Self { writer, notifications, stats, found }
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.writer.is_encrypted()
+    }
+
+    pub fn handle_hit(&self, record: FoundKey) {
+        self.found.store(true, Ordering::Relaxed);
+        self.stats.record_hit(record.backend, record.worker_id);
+
+        let (key_hex, key_dec) = self.writer.visible_key(&record);
+        let rate = match record.backend {
+            Backend::Cpu => self.stats.get_cpu_rate(),
+            Backend::Gpu => self.stats.get_gpu_rate(),
+        };
+
+        println!(
+            "\n🎉 ==========================================\n\
+             💰 ADRESSE TROUVÉE PAR {} ! 💰\n\
+             🔍 Adresse: {}\n\
+             🔢 Clé Privée (Hex): {}\n\
+             🔢 Clé Privée (Dec): {}\n\
+             ⚙️  Worker: {} {}\n\
+             ⚡ Vitesse {}: {:.2} k/s\n\
+             🕐 Temps écoulé: {:.2}s\n\
+             ==========================================\n",
+            record.backend, record.target, key_hex, key_dec,
+            record.backend, record.worker_id,
+            record.backend, rate / 1000.0, self.stats.start_time.elapsed().as_secs_f64()
+        );
+
+        events::emit(Level::Info, Event::Hit {
+            target: record.target.clone(),
+            backend: record.backend,
+            worker: record.worker_id,
+            address_type: record.address_type.clone(),
+        });
+
+        // La clé n'est affichée et notifiée qu'expurgée en mode chiffré : son écriture ne doit pas échouer
+        self.writer.write_or_rescue(&record);
+
+        let record = if self.is_encrypted() { record.redacted() } else { record };
+        self.notifications.send(Notification::KeyFound { record });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secret_key_from_ubig;
+    use ibig::ubig;
+
+    const WIF_COMPRESSED: &str = "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn";
+    const WIF_UNCOMPRESSED: &str = "5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsreAnchuDf";
+
+    // Clé 1 retrouvée par l'adresse (ou la clé publique) `target`
+    fn key_one(target: &str) -> FoundKey {
+        let secp = Secp256k1::new();
+        let secret_key = secret_key_from_ubig(&ubig!(1)).unwrap();
+        FoundKey::from_match(&secp, &secret_key, &ubig!(1), target, Backend::Gpu, 2, "sequential", (&ubig!(1), &ubig!(0xff)))
+    }
+
+    #[test]
+    fn record_of_key_one_has_both_wifs_and_the_matched_variant() {
+        let compressed = key_one("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
+        assert_eq!(compressed.wif_compressed, WIF_COMPRESSED);
+        assert_eq!(compressed.wif_uncompressed, WIF_UNCOMPRESSED);
+        assert_eq!(compressed.address_type, "p2pkh_compressed");
+        assert_eq!(compressed.public_key, "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+
+        let uncompressed = key_one("1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm");
+        assert_eq!((uncompressed.wif_compressed.as_str(), uncompressed.wif_uncompressed.as_str()), (WIF_COMPRESSED, WIF_UNCOMPRESSED));
+        assert_eq!(uncompressed.address_type, "p2pkh_uncompressed");
+        assert!(uncompressed.public_key.starts_with("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada77"));
+        assert!(uncompressed.summary_line().contains(WIF_UNCOMPRESSED));
+
+        let value = serde_json::to_value(&compressed).unwrap();
+        let fields: Vec<&str> = value.as_object().unwrap().keys().map(String::as_str).collect();
+        assert_eq!(fields.len(), 13);
+        for (field, expected) in [
+            ("target", "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"),
+            ("private_key_hex", "1"),
+            ("private_key_dec", "1"),
+            ("wif_compressed", WIF_COMPRESSED),
+            ("wif_uncompressed", WIF_UNCOMPRESSED),
+            ("address_type", "p2pkh_compressed"),
+            ("backend", "gpu"),
+            ("mode", "sequential"),
+            ("range_start", "0x1"),
+            ("range_end", "0xff"),
+        ] {
+            assert_eq!(value[field], expected, "champ {}", field);
+        }
+        assert_eq!(value["worker_id"], 2);
+        assert!(chrono::DateTime::parse_from_rfc3339(value["timestamp"].as_str().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn each_write_appends_one_json_line_and_a_summary_line() {
+        let dir = std::env::temp_dir().join(format!("solver_results_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let (results, summary) = (dir.join("found.jsonl"), dir.join("found.txt"));
+        let writer = ResultWriter::new(results.to_str().unwrap(), summary.to_str(), None);
+
+        writer.write(&key_one("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH")).unwrap();
+        writer.write(&key_one("1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm")).unwrap();
+
+        let records: Vec<FoundKey> = std::fs::read_to_string(&results)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].address_type, "p2pkh_compressed");
+        assert_eq!(records[1].address_type, "p2pkh_uncompressed");
+
+        let summary = std::fs::read_to_string(&summary).unwrap();
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains(WIF_COMPRESSED) && lines[0].contains("Clé (hex): 1,"));
+        assert!(lines[1].contains(WIF_UNCOMPRESSED) && lines[1].contains("1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,96 @@
+// Découpage de la plage de recherche entre les workers
+use ibig::{ubig, UBig};
+
+use crate::strategy::KeyMask;
+
+// Divise [start, end] en `parts` sous-plages contiguës ; la dernière absorbe le reste de la division
+pub fn split_range(start: &UBig, end: &UBig, parts: usize) -> Vec<(UBig, UBig)> {
+    let parts = parts.max(1);
+    let range_per_part = (end - start + ubig!(1)) / parts;
+
+    (0..parts)
+        .map(|i| {
+            let part_start = start + i * &range_per_part;
+            let part_end = if i == parts - 1 { end.clone() } else { &part_start + &range_per_part - ubig!(1) };
+            (part_start, part_end)
+        })
+        .collect()
+}
+
+// Divise la progression first, first + stride, ... (termes <= end) en au plus `parts` tranches d'indices
+// contiguës ; chaque tranche est décrite par son premier et son dernier terme, de sorte que chaque worker
+// reste sur la même progression. Moins de tranches que `parts` si la progression a moins de termes.
+pub fn split_progression(first: &UBig, end: &UBig, stride: &UBig, parts: usize) -> Vec<(UBig, UBig)> {
+    if first > end || *stride == ubig!(0) {
+        return Vec::new();
+    }
+    let terms = (end - first) / stride + ubig!(1);
+    let parts = usize::try_from(&terms).map_or(parts, |terms| parts.min(terms)).max(1);
+    let terms_per_part = &terms / parts;
+
+    (0..parts)
+        .map(|i| {
+            let first_index = i * &terms_per_part;
+            let next_index = if i == parts - 1 { terms.clone() } else { &first_index + &terms_per_part };
+            (first + first_index * stride, first + (next_index - ubig!(1)) * stride)
+        })
+        .collect()
+}
+
+// Divise les clés compatibles avec le masque dans [start, end] en au plus `parts` tranches d'indices
+// contiguës, décrites par leur première et leur dernière clé (les clés croissent avec l'indice)
+pub fn split_mask(mask: &KeyMask, start: &UBig, end: &UBig, parts: usize) -> Vec<(UBig, UBig)> {
+    let (first, last) = mask.index_range(start, end);
+    if first >= last {
+        return Vec::new();
+    }
+    let count = &last - &first;
+    let parts = usize::try_from(&count).map_or(parts, |count| parts.min(count)).max(1);
+    split_range(&first, &(last - ubig!(1)), parts)
+        .into_iter()
+        .map(|(a, b)| (mask.key_at(&a), mask.key_at(&b)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts_are_contiguous_and_cover_range() {
+        let parts = split_range(&ubig!(10), &ubig!(109), 3);
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0], (ubig!(10), ubig!(42)));
+        assert_eq!(parts[1], (ubig!(43), ubig!(75)));
+        assert_eq!(parts[2], (ubig!(76), ubig!(109)));
+    }
+
+    #[test]
+    fn progression_parts_stay_on_the_lattice() {
+        // 100 + 7i <= 1000 : 129 termes répartis sur 4 tranches
+        let parts = split_progression(&ubig!(100), &ubig!(1000), &ubig!(7), 4);
+        assert_eq!(parts, [(ubig!(100), ubig!(317)), (ubig!(324), ubig!(541)), (ubig!(548), ubig!(765)), (ubig!(772), ubig!(996))]);
+
+        let terms: Vec<UBig> = parts
+            .iter()
+            .flat_map(|(first, last)| (0..).map(move |i: u32| first + UBig::from(7 * i)).take_while(move |k| k <= last))
+            .collect();
+        let expected: Vec<UBig> = (0..129u32).map(|i| UBig::from(100 + 7 * i)).collect();
+        assert_eq!(terms, expected);
+    }
+
+    #[test]
+    fn short_progressions_use_fewer_parts() {
+        assert_eq!(split_progression(&ubig!(5), &ubig!(20), &ubig!(10), 8), [(ubig!(5), ubig!(5)), (ubig!(15), ubig!(15))]);
+        assert!(split_progression(&ubig!(30), &ubig!(20), &ubig!(10), 8).is_empty());
+    }
+
+    #[test]
+    fn mask_parts_cover_compatible_keys() {
+        let mask: KeyMask = "0x1?5?".parse().unwrap();
+        let parts = split_mask(&mask, &ubig!(0x1250), &ubig!(0x1a5f), 3);
+        assert_eq!(parts, [(ubig!(0x1250), ubig!(0x145f)), (ubig!(0x1550), ubig!(0x175f)), (ubig!(0x1850), ubig!(0x1a5f))]);
+        assert_eq!(split_mask(&mask, &ubig!(0x1250), &ubig!(0x1251), 3), [(ubig!(0x1250), ubig!(0x1250)), (ubig!(0x1251), ubig!(0x1251))]);
+        assert!(split_mask(&mask, &ubig!(0x2000), &ubig!(0x3000), 3).is_empty());
+    }
+}
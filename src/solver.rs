@@ -0,0 +1,367 @@
+// Orchestration d'une recherche complète : workers, API, surveillance et journal d'événements
+use bitcoin::secp256k1::Secp256k1;
+use ibig::{ubig, UBig};
+use rand::Rng;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::api::{start_api, ApiState};
+use crate::batch::{calibrate_batch_size, CALIBRATION_SIZES};
+use crate::config::{parse_big_int, ComputeMode, Config};
+use crate::encryption::ResultEncryption;
+use crate::events::{self, Event, Level};
+use crate::gpu::GPUInfo;
+use crate::monitor::{format_duration, format_eta, install_panic_alert, Monitor};
+use crate::notify::{DispatcherSettings, Notification, NotificationDispatcher, Notifier};
+use crate::results::{ResultHandler, ResultWriter};
+use crate::schedule::{split_mask, split_progression, split_range};
+use crate::stats::{Control, Statistics};
+use crate::strategy::{ScriptGenerator, SearchMode};
+use crate::targets::TargetSet;
+use crate::u256::U256;
+use crate::worker::{cpu_worker_thread, gpu_worker_thread, pubkey_worker_thread, spawn_worker, WorkerContext};
+
+// Bilan d'une recherche terminée
+#[derive(Debug, Clone)]
+pub struct SearchOutcome {
+    pub found: u64,
+    pub keys_checked: u64,
+    pub elapsed: Duration,
+    pub average_rate: f64,
+    pub peak_rate: f64,
+    // Notifications non livrées conservées dans `notify_outbox`
+    pub undelivered: u64,
+    // Notifications perdues (outbox impossible à écrire)
+    pub dropped: u64,
+}
+
+fn emit_stats_event(monitor: &Monitor, stats: &Statistics) {
+    if events::enabled(Level::Info) {
+        events::emit(Level::Info, Event::Stats {
+            report: monitor.report(stats),
+            workers: stats.workers().iter().map(|w| w.snapshot()).collect(),
+        });
+    }
+}
+
+// Plages des workers CPU : découpage contigu, ou par tranches de la progression start + stride_offset + i * stride
+// (mode stride) ou des clés compatibles avec le masque (mode mask), chaque worker commençant sur une clé à tester
+fn cpu_partitions(config: &Config, start: &UBig, end: &UBig, parts: usize) -> Vec<(UBig, UBig)> {
+    match config.mode {
+        SearchMode::Stride => split_progression(&(start + &config.stride_offset), end, &config.stride, parts),
+        SearchMode::Mask => match &config.mask {
+            Some(mask) => split_mask(mask, start, end, parts),
+            None => Vec::new(),
+        },
+        _ => split_range(start, end, parts),
+    }
+}
+
+// Lance la recherche décrite par `config` et bloque jusqu'à la fin des workers
+pub fn run_search(config: Arc<Config>, targets: Arc<TargetSet>, notifiers: Vec<Box<dyn Notifier>>) -> Result<SearchOutcome, String> {
+    if targets.is_empty() {
+        return Err(format!("Le fichier puzzle '{}' est vide ou n'a pas pu être lu.", config.puzzle_file));
+    }
+
+    // Graine fixée par `seed` ou tirée au hasard : toujours affichée et journalisée pour rejouer la recherche
+    let seed = config.seed.unwrap_or_else(|| rand::thread_rng().gen());
+    let config = Arc::new(Config { seed: Some(seed), ..(*config).clone() });
+
+    // `batch_size=0` : taille des lots CPU choisie par une courte calibration sur ce processeur
+    let calibrated = config.batch_size == 0;
+    let config = if calibrated {
+        let batch_size = calibrate_batch_size(&Secp256k1::new(), &CALIBRATION_SIZES);
+        Arc::new(Config { batch_size, ..(*config).clone() })
+    } else {
+        config
+    };
+
+    let start_key = parse_big_int(&config.start).map_err(|e| format!("Clé de départ invalide: {}", e))?;
+    let end_key = parse_big_int(&config.end).map_err(|e| format!("Clé de fin invalide: {}", e))?;
+
+    if start_key >= end_key {
+        return Err("La clé de départ doit être inférieure à la clé de fin.".to_string());
+    }
+    // Les clés sont manipulées sur 256 bits pendant la recherche
+    if U256::try_from(&end_key).map_or(true, |end| end >= U256::CURVE_ORDER) {
+        return Err("La clé de fin dépasse l'ordre de la courbe secp256k1.".to_string());
+    }
+
+    if config.mode == SearchMode::Stride && &start_key + &config.stride_offset > end_key {
+        return Err("Le décalage du mode stride (stride_offset) dépasse la plage de recherche.".to_string());
+    }
+
+    // Les modes bsgs et kangaroo résolvent directement les cibles données par leur clé publique
+    let public_keys = targets.public_keys().len();
+    if config.mode == SearchMode::Bsgs && public_keys == 0 {
+        return Err("Le mode bsgs nécessite au moins une clé publique cible dans le fichier puzzle.".to_string());
+    }
+    let pubkey_search = matches!(config.mode, SearchMode::Bsgs | SearchMode::Kangaroo) && public_keys > 0;
+
+    // Volume de travail : clés de la plage, ou seulement celles compatibles avec le masque ou sur la progression
+    let search_size = match (config.mode, &config.mask) {
+        (SearchMode::Mask, Some(mask)) => mask.count_in(&start_key, &end_key),
+        (SearchMode::Mask, None) => return Err("Mode mask sélectionné sans masque (option `mask`).".to_string()),
+        // Termes start + offset + k * stride de la plage (le décalage est déjà borné par la plage)
+        (SearchMode::Stride, _) => (&end_key - &start_key - &config.stride_offset) / &config.stride + ubig!(1),
+        _ => &end_key - &start_key + ubig!(1),
+    };
+    if search_size == ubig!(0) {
+        return Err("Aucune clé compatible avec le masque dans la plage de recherche.".to_string());
+    }
+
+    // Un script de candidats invalide arrête la recherche avant le démarrage des workers
+    let smart_script = match (config.mode, config.smart_jump, &config.smart_script) {
+        (SearchMode::Smart, true, Some(path)) => ScriptGenerator::load(path).map(|_| Some(path))?,
+        _ => None,
+    };
+
+    match events::init(&config) {
+        Ok(true) => events::emit(Level::Info, Event::Start {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            pid: std::process::id(),
+        }),
+        Ok(false) => {},
+        Err(e) => eprintln!("⚠️  {}", e),
+    }
+
+    let stats = Arc::new(Statistics::new());
+    let found = Arc::new(AtomicBool::new(false));
+    let control = Arc::new(Control::default());
+    let encryption = match (&config.results_encryption_recipient, config.results_encryption_passphrase) {
+        (Some(recipient), passphrase) => {
+            if passphrase {
+                eprintln!("⚠️  results_encryption_recipient et results_encryption_passphrase définis : la clé publique est utilisée.");
+            }
+            Some(ResultEncryption::from_recipient(recipient)?)
+        },
+        (None, true) => Some(ResultEncryption::prompt_passphrase()?),
+        (None, false) => None,
+    };
+    let writer = ResultWriter::new(&config.results_file, config.summary_file.as_deref(), encryption);
+    let notifications = Arc::new(NotificationDispatcher::start(notifiers, DispatcherSettings::from_config(&config)));
+    let results = Arc::new(ResultHandler::new(writer, notifications.clone(), stats.clone(), found.clone()));
+    install_panic_alert(notifications.clone());
+    let ctx = WorkerContext {
+        config: config.clone(),
+        targets: targets.clone(),
+        stats: stats.clone(),
+        found: found.clone(),
+        results: results.clone(),
+        control: control.clone(),
+    };
+    let mut handles = vec![];
+
+    let total_threads = if config.cores == 0 { num_cpus::get() } else { config.cores };
+
+    println!("\nConfiguration de la recherche :");
+    println!("  - Plage de clés : {} -> {}", start_key, end_key);
+    println!("  - Mode de calcul: {:?}", config.compute_mode);
+    println!("  - Mode de recherche: {}", config.mode);
+    if let Some(path) = smart_script {
+        println!("  - Script de candidats: {}", path);
+    }
+    if matches!(config.mode, SearchMode::Random | SearchMode::Smart) && config.subinterval_ratio > 0.0 {
+        println!("  - Sous-intervalles: {}% de la plage de chaque coeur", config.subinterval_ratio * 100.0);
+    }
+    if config.mode == SearchMode::Stride {
+        println!("  - Pas: {} (décalage {}, {} clés dans la plage)", config.stride, config.stride_offset, search_size);
+    }
+    if let (SearchMode::Mask, Some(mask)) = (config.mode, &config.mask) {
+        println!("  - Masque: {} ({} bits libres, {} clés dans la plage)", mask, mask.free_bits(), search_size);
+    }
+    if pubkey_search {
+        println!("  - Symétrie de négation: {}", if config.negation_symmetry { "activée" } else { "désactivée" });
+        if config.mode == SearchMode::Bsgs {
+            println!("  - Table bsgs: {} pas de bébé", config.bsgs_table_size);
+        }
+    } else {
+        println!("  - Taille des lots CPU: {}{}", config.batch_size, if calibrated { " (calibrée)" } else { "" });
+    }
+    println!("  - Graine: {}", seed);
+    println!("  - Adresses à trouver: {}", targets.len());
+    if public_keys > 0 {
+        println!("  - Clés publiques cibles: {}", public_keys);
+    }
+    if pubkey_search && targets.len() > public_keys {
+        eprintln!("⚠️  {} adresse(s) sans clé publique connue ignorée(s) en mode {}.", targets.len() - public_keys, config.mode);
+    }
+    if results.is_encrypted() {
+        println!("  - Résultats chiffrés dans '{}' (aucune clé stockée en clair)", config.results_file);
+    }
+    if !notifications.notifier_names().is_empty() {
+        println!("  - Notifications: {}", notifications.notifier_names().join(", "));
+    }
+    if let Some(event_log) = &config.event_log {
+        println!("  - Journal d'événements: {}", event_log);
+    }
+    events::emit(Level::Info, Event::Config {
+        mode: config.mode.to_string(),
+        compute_mode: format!("{:?}", config.compute_mode).to_lowercase(),
+        range_start: format!("0x{:x}", start_key),
+        range_end: format!("0x{:x}", end_key),
+        range_size: format!("0x{:x}", search_size),
+        threads: total_threads,
+        targets: targets.len(),
+        seed: Some(seed),
+    });
+    
+    let gpu_info = GPUInfo::detect();
+
+    // --- Démarrage des threads ---
+    match config.compute_mode {
+        // Recherche par clé publique : un seul worker, qui répartit chaque recherche sur tous les threads
+        _ if pubkey_search => {
+            println!("  - Démarrage de la recherche par clé publique sur {} threads CPU...", total_threads);
+            let (ctx, sk, ek) = (ctx.clone(), start_key.clone(), end_key.clone());
+            handles.push(spawn_worker("pubkey".to_string(), move || {
+                pubkey_worker_thread(ctx, sk, ek, total_threads);
+            }));
+        },
+        ComputeMode::CPU => {
+            println!("  - Démarrage de {} threads CPU...", total_threads);
+            for (i, (core_start, core_end)) in cpu_partitions(&config, &start_key, &end_key, total_threads).into_iter().enumerate() {
+                let ctx = ctx.clone();
+                handles.push(spawn_worker(format!("cpu-{}", i), move || {
+                    cpu_worker_thread(i, ctx, core_start, core_end);
+                }));
+            }
+        },
+        ComputeMode::GPU => {
+            if !gpu_info.available {
+                return Err("Mode GPU sélectionné mais aucun GPU compatible n'a été détecté.".to_string());
+            }
+            println!("  - Démarrage de 1 thread GPU (simulation)...");
+            let device_id = config.gpu_device_id;
+            let (ctx, sk, ek) = (ctx.clone(), start_key.clone(), end_key.clone());
+            handles.push(spawn_worker(format!("gpu-{}", device_id), move || {
+                gpu_worker_thread(device_id, ctx, sk, ek);
+            }));
+        },
+        ComputeMode::Hybrid => {
+            if !gpu_info.available {
+                eprintln!("Avertissement: Mode hybride sélectionné, mais aucun GPU détecté. Passage en mode CPU uniquement.");
+                // Comportement identique au mode CPU
+            }
+
+            let num_cpu_threads = (total_threads as f64 * config.cpu_gpu_ratio).ceil() as usize;
+            let num_gpu_threads = if gpu_info.available { total_threads - num_cpu_threads } else { 0 };
+
+            println!("  - Mode Hybride: {} threads CPU, {} threads GPU", num_cpu_threads, num_gpu_threads);
+
+            // Threads CPU
+            if num_cpu_threads > 0 {
+                for (i, (core_start, core_end)) in cpu_partitions(&config, &start_key, &end_key, num_cpu_threads).into_iter().enumerate() {
+                    let ctx = ctx.clone();
+                    handles.push(spawn_worker(format!("cpu-{}", i), move || {
+                        cpu_worker_thread(i, ctx, core_start, core_end);
+                    }));
+                }
+            }
+
+            // Threads GPU
+            for i in 0..num_gpu_threads {
+                 let (ctx, sk, ek) = (ctx.clone(), start_key.clone(), end_key.clone());
+                 handles.push(spawn_worker(format!("gpu-{}", i), move || {
+                     gpu_worker_thread(i, ctx, sk, ek);
+                 }));
+            }
+        }
+    }
+
+    if let Some(listen) = &config.api_listen {
+        let state = ApiState {
+            config: config.clone(),
+            stats: stats.clone(),
+            control: control.clone(),
+            notifications: notifications.clone(),
+            range_size: search_size.clone(),
+        };
+        match start_api(listen, state) {
+            Ok((address, _)) => println!("  - API HTTP: http://{0}/status (métriques: http://{0}/metrics)", address),
+            Err(e) => eprintln!("⚠️  {}", e),
+        }
+    }
+
+    println!("\nRecherche en cours... Pressez CTRL+C pour arrêter.");
+
+    // Boucle principale pour afficher les statistiques
+    let start_time = stats.start_time;
+    let mut monitor = Monitor::new(&config, &search_size);
+    let mut last_stats_event = Instant::now();
+    while handles.iter().any(|h| !h.is_finished()) {
+        // Échantillonnage des compteurs chaque seconde pour les débits glissants
+        thread::sleep(Duration::from_secs(1));
+        let paused = control.paused.load(Ordering::Relaxed);
+        stats.sample(paused);
+        
+        if found.load(Ordering::Relaxed) && config.stop_on_find {
+            break;
+        }
+
+        for notification in monitor.tick(&stats, paused) {
+            if let Notification::Alert { message, .. } = &notification {
+                events::emit(Level::Warn, Event::Alert { message: message.clone() });
+            }
+            notifications.send(notification);
+        }
+        if config.event_log_interval > 0 && last_stats_event.elapsed() >= Duration::from_secs(config.event_log_interval) {
+            last_stats_event = Instant::now();
+            emit_stats_event(&monitor, &stats);
+        }
+
+        let elapsed_time = format_duration(start_time.elapsed().as_secs());
+        let total_rate = stats.get_rate();
+        let cpu_rate = stats.get_cpu_rate();
+        let gpu_rate = stats.get_gpu_rate();
+        let peak_rate = stats.rate_trackers()[0].peak();
+        let stalled = stats.workers().iter().filter(|w| w.is_stalled()).count();
+        let report = monitor.report(&stats);
+        let progress = match report.eta_secs {
+            Some(eta) => format!("Couverture: {:.4}% | Fin: {}", report.coverage * 100.0, format_eta(eta)),
+            None => format!("P(succès): {:.6}%", report.success_probability * 100.0),
+        };
+        
+        print!("\r[Temps: {}] [Total: {:.2} Mk/s (pic {:.2})] [CPU: {:.2} Mk/s | GPU: {:.2} Mk/s] [{}] [Trouvées: {}]{}      ",
+            elapsed_time, 
+            total_rate / 1_000_000.0,
+            peak_rate / 1_000_000.0,
+            cpu_rate / 1_000_000.0,
+            gpu_rate / 1_000_000.0,
+            progress,
+            stats.found_count.load(Ordering::Relaxed),
+            if stalled > 0 { format!(" [⚠️  Workers bloqués: {}]", stalled) } else { String::new() }
+        );
+        let _ = std::io::stdout().flush();
+    }
+
+    println!("\n\nRecherche terminée.");
+    for handle in handles {
+        let name = handle.thread().name().unwrap_or("worker").to_string();
+        if handle.join().is_err() {
+            eprintln!("⚠️  Le thread '{}' s'est arrêté sur une erreur.", name);
+        }
+    }
+    emit_stats_event(&monitor, &stats);
+    events::emit(Level::Info, Event::End {
+        elapsed_secs: stats.start_time.elapsed().as_secs(),
+        keys_checked: stats.keys_checked.load(Ordering::Relaxed),
+        average_rate: stats.get_average_rate(),
+        found: stats.found_count.load(Ordering::Relaxed),
+    });
+    notifications.shutdown();
+
+    let peak_rate = stats.rate_trackers()[0].peak();
+    Ok(SearchOutcome {
+        found: stats.found_count.load(Ordering::Relaxed),
+        keys_checked: stats.keys_checked.load(Ordering::Relaxed),
+        elapsed: stats.start_time.elapsed(),
+        average_rate: stats.get_average_rate(),
+        peak_rate,
+        undelivered: notifications.counters().persisted.load(Ordering::Relaxed),
+        dropped: notifications.counters().dropped.load(Ordering::Relaxed),
+    })
+}
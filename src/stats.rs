@@ -0,0 +1,259 @@
+// Compteurs partagés, état des workers et commandes de pilotage
+use ibig::{ubig, UBig};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::Instant;
+
+use crate::events::{self, Event, Level, WorkerSnapshot};
+use crate::rates::{RateTracker, SHORT_WINDOW};
+use crate::results::Backend;
+
+#[derive(Debug)]
+pub struct Statistics {
+    pub start_time: Instant,
+    pub keys_checked: AtomicU64,
+    pub found_count: AtomicU64,
+    pub cpu_keys_checked: AtomicU64,
+    pub gpu_keys_checked: AtomicU64,
+    workers: Mutex<Vec<Arc<WorkerStats>>>,
+    // Débits glissants (total, CPU, GPU), échantillonnés par la boucle principale
+    rates: Mutex<[RateTracker; 3]>,
+}
+
+// Compteurs et position courante d'un worker
+#[derive(Debug)]
+pub struct WorkerStats {
+    pub backend: Backend,
+    pub id: usize,
+    pub range_start: UBig,
+    pub range_end: UBig,
+    pub keys_checked: AtomicU64,
+    pub hits: AtomicU64,
+    pub checkpoints_written: AtomicU64,
+    position: Mutex<UBig>,
+    rates: Mutex<RateTracker>,
+    // Positionné par le détecteur de blocage lorsque le compteur n'avance plus
+    pub(crate) stalled: AtomicBool,
+    // Le thread du worker est terminé (plage épuisée, arrêt demandé ou clé trouvée)
+    finished: AtomicBool,
+}
+
+impl WorkerStats {
+    pub fn add_keys(&self, count: u64) {
+        self.keys_checked.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub fn set_position(&self, position: &UBig) {
+        *self.position.lock().unwrap_or_else(|e| e.into_inner()) = position.clone();
+    }
+
+    pub fn position(&self) -> UBig {
+        self.position.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    // Avancement de la position courante dans la plage du worker (0.0 - 1.0)
+    pub fn position_ratio(&self) -> f64 {
+        let position = self.position();
+        let span = (&self.range_end - &self.range_start + ubig!(1)).to_f64();
+        let done = if position > self.range_start { (&position - &self.range_start).to_f64() } else { 0.0 };
+        if span > 0.0 { (done / span).min(1.0) } else { 0.0 }
+    }
+
+    pub fn rates(&self) -> MutexGuard<'_, RateTracker> {
+        self.rates.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn is_stalled(&self) -> bool {
+        self.stalled.load(Ordering::Relaxed)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Relaxed)
+    }
+
+    // Marque le worker comme terminé lorsque la garde est détruite, quelle que soit la sortie du thread
+    pub fn finish_guard(self: &Arc<Self>, control: &Arc<Control>, found: &Arc<AtomicBool>) -> WorkerFinishGuard {
+        WorkerFinishGuard { worker: self.clone(), control: control.clone(), found: found.clone() }
+    }
+
+    pub fn snapshot(&self) -> WorkerSnapshot {
+        WorkerSnapshot {
+            backend: self.backend,
+            worker: self.id,
+            range_start: format!("0x{:x}", self.range_start),
+            range_end: format!("0x{:x}", self.range_end),
+            position: format!("0x{:x}", self.position()),
+            keys_checked: self.keys_checked.load(Ordering::Relaxed),
+            rate: self.rates().rate(SHORT_WINDOW).unwrap_or(0.0),
+            stalled: self.is_stalled(),
+        }
+    }
+}
+
+pub struct WorkerFinishGuard {
+    worker: Arc<WorkerStats>,
+    control: Arc<Control>,
+    found: Arc<AtomicBool>,
+}
+
+impl Drop for WorkerFinishGuard {
+    fn drop(&mut self) {
+        let worker = &self.worker;
+        worker.finished.store(true, Ordering::Relaxed);
+
+        let reason = if thread::panicking() {
+            "panic"
+        } else if worker.hits.load(Ordering::Relaxed) > 0 {
+            "key_found"
+        } else if self.control.stop.load(Ordering::Relaxed) {
+            "stopped"
+        } else if self.found.load(Ordering::Relaxed) {
+            "stop_on_find"
+        } else {
+            "completed"
+        };
+        events::emit(if reason == "panic" { Level::Error } else { Level::Info }, Event::WorkerExit {
+            backend: worker.backend,
+            worker: worker.id,
+            reason: reason.to_string(),
+            keys_checked: worker.keys_checked.load(Ordering::Relaxed),
+            position: format!("0x{:x}", worker.position()),
+        });
+    }
+}
+
+// Commandes de pilotage partagées entre l'API HTTP et les workers
+#[derive(Debug, Default)]
+pub struct Control {
+    pub paused: AtomicBool,
+    pub stop: AtomicBool,
+    // Incrémenté à chaque demande de point de contrôle immédiat
+    pub checkpoint_requests: AtomicU64,
+}
+
+impl Control {
+    pub fn state(&self) -> &'static str {
+        if self.stop.load(Ordering::Relaxed) {
+            "stopping"
+        } else if self.paused.load(Ordering::Relaxed) {
+            "paused"
+        } else {
+            "running"
+        }
+    }
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Statistics {
+    pub fn new() -> Self {
+        Self {
+            start_time: Instant::now(),
+            keys_checked: AtomicU64::new(0),
+            found_count: AtomicU64::new(0),
+            cpu_keys_checked: AtomicU64::new(0),
+            gpu_keys_checked: AtomicU64::new(0),
+            workers: Mutex::new(Vec::new()),
+            rates: Mutex::new(std::array::from_fn(|_| RateTracker::new(Instant::now()))),
+        }
+    }
+
+    pub fn register_worker(&self, backend: Backend, id: usize, range_start: &UBig, range_end: &UBig) -> Arc<WorkerStats> {
+        let worker = Arc::new(WorkerStats {
+            backend,
+            id,
+            range_start: range_start.clone(),
+            range_end: range_end.clone(),
+            keys_checked: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            checkpoints_written: AtomicU64::new(0),
+            position: Mutex::new(range_start.clone()),
+            rates: Mutex::new(RateTracker::new(Instant::now())),
+            stalled: AtomicBool::new(false),
+            finished: AtomicBool::new(false),
+        });
+        self.workers.lock().unwrap_or_else(|e| e.into_inner()).push(worker.clone());
+        worker
+    }
+
+    pub fn workers(&self) -> Vec<Arc<WorkerStats>> {
+        self.workers.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn record_hit(&self, backend: Backend, id: usize) {
+        self.found_count.fetch_add(1, Ordering::Relaxed);
+        if let Some(worker) = self.workers().iter().find(|w| w.backend == backend && w.id == id) {
+            worker.hits.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    #[allow(dead_code)]
+    pub fn add_keys(&self, count: u64) {
+        self.keys_checked.fetch_add(count, Ordering::Relaxed);
+    }
+    
+    pub fn add_cpu_keys(&self, count: u64) {
+        self.cpu_keys_checked.fetch_add(count, Ordering::Relaxed);
+        self.keys_checked.fetch_add(count, Ordering::Relaxed);
+    }
+    
+    pub fn add_gpu_keys(&self, count: u64) {
+        self.gpu_keys_checked.fetch_add(count, Ordering::Relaxed);
+        self.keys_checked.fetch_add(count, Ordering::Relaxed);
+    }
+    
+    // Échantillonne tous les compteurs ; appelé chaque seconde par la boucle principale
+    pub fn sample(&self, paused: bool) {
+        let now = Instant::now();
+        {
+            let mut rates = self.rates.lock().unwrap_or_else(|e| e.into_inner());
+            rates[0].record(now, self.keys_checked.load(Ordering::Relaxed), paused);
+            rates[1].record(now, self.cpu_keys_checked.load(Ordering::Relaxed), paused);
+            rates[2].record(now, self.gpu_keys_checked.load(Ordering::Relaxed), paused);
+        }
+        for worker in self.workers() {
+            worker.rates().record(now, worker.keys_checked.load(Ordering::Relaxed), paused);
+        }
+    }
+
+    pub fn rate_trackers(&self) -> MutexGuard<'_, [RateTracker; 3]> {
+        self.rates.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // Vitesse sur la fenêtre courte ; moyenne depuis le démarrage tant qu'aucun échantillon n'existe
+    pub fn windowed_rate(&self, index: usize, counter: &AtomicU64) -> f64 {
+        self.rate_trackers()[index]
+            .rate(SHORT_WINDOW)
+            .unwrap_or_else(|| self.average_rate(counter))
+    }
+
+    pub fn average_rate(&self, counter: &AtomicU64) -> f64 {
+        let elapsed = self.start_time.elapsed().as_secs_f64();
+        if elapsed > 0.0 {
+            counter.load(Ordering::Relaxed) as f64 / elapsed
+        } else {
+            0.0
+        }
+    }
+
+    pub fn get_rate(&self) -> f64 {
+        self.windowed_rate(0, &self.keys_checked)
+    }
+
+    pub fn get_cpu_rate(&self) -> f64 {
+        self.windowed_rate(1, &self.cpu_keys_checked)
+    }
+
+    pub fn get_gpu_rate(&self) -> f64 {
+        self.windowed_rate(2, &self.gpu_keys_checked)
+    }
+
+    pub fn get_average_rate(&self) -> f64 {
+        self.average_rate(&self.keys_checked)
+    }
+}
+
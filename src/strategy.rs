@@ -0,0 +1,425 @@
+// Stratégies de recherche : ordre dans lequel chaque worker parcourt sa plage de clés
+use ibig::{ubig, UBig};
+use rand::RngCore;
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::config::{parse_big_int, Config};
+use crate::u256::U256;
+
+mod kangaroo;
+mod mask;
+mod patterns;
+mod random;
+mod script;
+mod sequential;
+mod shuffle;
+mod smart;
+mod stride;
+
+pub use kangaroo::KangarooStrategy;
+pub use mask::{KeyMask, MaskStrategy};
+pub use patterns::{generate_key_patterns, parse_pattern_families, PatternFamily};
+pub use random::RandomStrategy;
+pub use script::{ScriptGenerator, MAX_SCRIPT_CANDIDATES};
+pub use sequential::SequentialStrategy;
+pub use shuffle::{BlockPermutation, ShuffleStrategy};
+pub use smart::SmartStrategy;
+pub use stride::StrideStrategy;
+
+// Nombre maximal de clés produites par lot (les commandes de pilotage sont vérifiées entre deux lots)
+pub const CHUNK_SIZE: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchMode {
+    Sequential,
+    Random,
+    Smart,
+    Stride,
+    Kangaroo,
+    Shuffle,
+    Mask,
+    Bsgs,
+}
+
+impl SearchMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchMode::Sequential => "sequential",
+            SearchMode::Random => "random",
+            SearchMode::Smart => "smart",
+            SearchMode::Stride => "stride",
+            SearchMode::Kangaroo => "kangaroo",
+            SearchMode::Shuffle => "shuffle",
+            SearchMode::Mask => "mask",
+            SearchMode::Bsgs => "bsgs",
+        }
+    }
+}
+
+impl fmt::Display for SearchMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SearchMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "sequential" => Ok(SearchMode::Sequential),
+            "random" => Ok(SearchMode::Random),
+            "smart" => Ok(SearchMode::Smart),
+            "stride" => Ok(SearchMode::Stride),
+            "kangaroo" => Ok(SearchMode::Kangaroo),
+            "shuffle" => Ok(SearchMode::Shuffle),
+            "mask" => Ok(SearchMode::Mask),
+            "bsgs" => Ok(SearchMode::Bsgs),
+            _ => Err(format!("Mode de recherche invalide: {}. Utilisez 'sequential', 'random', 'smart', 'stride', 'kangaroo', 'shuffle', 'mask' ou 'bsgs'", s)),
+        }
+    }
+}
+
+// Parcours d'une plage [start, end] par un worker
+pub trait SearchStrategy: Send {
+    fn mode(&self) -> SearchMode;
+
+    // Ajoute à `batch` les prochaines clés candidates ; false lorsque la plage est épuisée
+    fn next_batch(&mut self, batch: &mut Vec<U256>) -> bool;
+
+    // Prochaine clé à tester, publiée dans les statistiques et les événements
+    fn position(&self) -> UBig;
+
+    // Écart constant entre deux clés consécutives d'un même lot, s'il existe : le worker calcule
+    // alors les clés publiques par addition de points (P + pas·G) au lieu d'une multiplication par clé
+    fn step(&self) -> Option<U256> {
+        None
+    }
+
+    // Paramètres qui définissent la suite de clés en plus du mode et de la plage (pas, masque...) :
+    // un point de contrôle écrit avec d'autres paramètres est ignoré
+    fn parameters(&self) -> serde_json::Value {
+        serde_json::Value::Null
+    }
+
+    // Un point de contrôle écrit avec `saved` peut-il être repris ? Par défaut, les paramètres doivent
+    // être identiques ; une stratégie peut accepter ceux qu'elle reprend de l'état (clé de permutation)
+    fn accepts_parameters(&self, saved: &serde_json::Value) -> bool {
+        *saved == self.parameters()
+    }
+
+    // État sérialisé pour les points de contrôle. Chaque état contient au moins `position` :
+    // un ancien point de contrôle (simple clé) est restauré comme `{"position": "0x..."}`.
+    fn state(&self) -> serde_json::Value;
+
+    fn restore(&mut self, state: &serde_json::Value) -> Result<(), String>;
+}
+
+// Flux aléatoires des workers GPU, distincts de ceux des workers CPU
+pub const GPU_STREAM: u64 = 1 << 32;
+
+// Graine propre à un worker (`stream` = id du worker, + GPU_STREAM pour un GPU), dérivée
+// de la graine de la recherche par mélange splitmix64
+pub fn worker_seed(seed: u64, stream: u64) -> u64 {
+    let mut z = seed ^ stream.wrapping_add(1).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+// Stratégie du worker `core_id` sur [start, end] selon `config.mode`. Avec la même graine
+// (`config.seed`), la suite des clés candidates est identique d'une exécution à l'autre.
+// Échoue si le script de candidats (`smart_script`) ne peut pas être chargé, sans `mask` en mode mask,
+// ou en mode bsgs (recherche par clé publique, voir `dlp`).
+pub fn build_strategy(config: &Config, core_id: usize, start: UBig, end: UBig) -> Result<Box<dyn SearchStrategy>, String> {
+    let seed = config.seed.unwrap_or_else(|| rand::thread_rng().next_u64());
+    let rng = FastRng::new(worker_seed(seed, core_id as u64));
+    Ok(match config.mode {
+        SearchMode::Sequential => Box::new(SequentialStrategy::new(start, end)),
+        SearchMode::Random => Box::new(
+            RandomStrategy::new(start, end, config.switch_interval, rng).with_subinterval_ratio(config.subinterval_ratio),
+        ),
+        SearchMode::Smart => {
+            // Sans `smart_jump`, le mode smart se réduit au parcours aléatoire
+            let families = if config.smart_jump { config.smart_patterns.clone() } else { Vec::new() };
+            let mut strategy = SmartStrategy::new(start, end, config.switch_interval, families, rng)
+                .with_subinterval_ratio(config.subinterval_ratio);
+            if let (true, Some(path)) = (config.smart_jump, &config.smart_script) {
+                strategy = strategy.with_script(ScriptGenerator::load(path)?);
+            }
+            Box::new(strategy)
+        },
+        SearchMode::Stride => Box::new(StrideStrategy::new(start, end, config.stride.clone())),
+        SearchMode::Kangaroo => Box::new(KangarooStrategy::new(start, end, rng)),
+        SearchMode::Shuffle => Box::new(ShuffleStrategy::new(start, end, config.switch_interval, rng)),
+        SearchMode::Mask => {
+            let mask = config.mask.clone().ok_or("Mode mask sans masque (option `mask`)")?;
+            Box::new(MaskStrategy::new(mask, start, end))
+        },
+        SearchMode::Bsgs => return Err("Le mode bsgs ne s'applique qu'aux cibles de type clé publique".to_string()),
+    })
+}
+
+fn hex(value: &UBig) -> String {
+    format!("0x{:x}", value)
+}
+
+fn state_field(state: &serde_json::Value, field: &str) -> Result<Option<UBig>, String> {
+    match state.get(field).and_then(|value| value.as_str()) {
+        Some(value) => parse_big_int(value)
+            .map(Some)
+            .map_err(|e| format!("Point de contrôle invalide ({}): {}", field, e)),
+        None => Ok(None),
+    }
+}
+
+// État d'un générateur (4 mots de 64 bits) ; un champ d'une autre forme (ancien générateur congruentiel,
+// un seul entier) est ignoré
+fn rng_field(state: &serde_json::Value, field: &str) -> Result<Option<FastRng>, String> {
+    match state.get(field).and_then(|v| v.as_array()) {
+        Some(words) => {
+            let words: Vec<u64> = words.iter().filter_map(|w| w.as_u64()).collect();
+            let rng = <[u64; 4]>::try_from(words).ok().and_then(FastRng::from_state);
+            Ok(Some(rng.ok_or_else(|| format!("Point de contrôle invalide ({})", field))?))
+        },
+        None => Ok(None),
+    }
+}
+
+// Générateur pseudo-aléatoire xoshiro256** (Blackman & Vigna) : rapide, période 2^256 - 1,
+// sans les bits de poids faible à courte période d'un générateur congruentiel.
+// Non cryptographique : il ne sert qu'à choisir l'ordre de parcours des clés.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastRng {
+    state: [u64; 4],
+}
+
+impl FastRng {
+    // L'état de 256 bits est dérivé de la graine par splitmix64 (jamais entièrement nul)
+    pub fn new(seed: u64) -> Self {
+        let mut z = seed;
+        let mut state = [0u64; 4];
+        for word in state.iter_mut() {
+            z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut x = z;
+            x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            *word = x ^ (x >> 31);
+        }
+        Self { state }
+    }
+
+    // État complet, pour les points de contrôle
+    pub fn state(&self) -> [u64; 4] {
+        self.state
+    }
+
+    // Un état entièrement nul est un point fixe du générateur : refusé
+    pub fn from_state(state: [u64; 4]) -> Option<Self> {
+        (state != [0; 4]).then_some(Self { state })
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    // Entier uniforme dans [0, bound) par rejet : on tire autant de bits que `bound - 1`
+    // en compte et on recommence si la valeur dépasse (moins de 2 tirages en moyenne),
+    // sans biais de modulo quelle que soit la taille de la plage (jusqu'à 256 bits et au-delà)
+    pub fn gen_below(&mut self, bound: &UBig) -> UBig {
+        if *bound <= ubig!(1) { return ubig!(0); }
+        let bits = (bound - ubig!(1)).bit_len();
+        if bits <= 64 {
+            // bound = 2^64 : toute valeur de 64 bits convient
+            let Ok(bound) = u64::try_from(bound) else { return UBig::from(self.next_u64()) };
+            let mask = u64::MAX >> (64 - bits);
+            loop {
+                let value = self.next_u64() & mask;
+                if value < bound { return UBig::from(value); }
+            }
+        }
+        let words = bits.div_ceil(64);
+        let excess = words * 64 - bits;
+        loop {
+            let mut value = ubig!(0);
+            for i in 0..words {
+                let mut word = self.next_u64();
+                if i == 0 { word >>= excess; }
+                value = (value << 64) | UBig::from(word);
+            }
+            if value < *bound { return value; }
+        }
+    }
+}
+
+impl FastRng {
+    // Variante de `gen_below` sur 256 bits sans allocation, pour les tirages par clé
+    pub fn gen_below_key(&mut self, bound: &U256) -> U256 {
+        if *bound <= U256::ONE { return U256::ZERO; }
+        let bits = (*bound - U256::ONE).bit_len();
+        let mut bytes = [0u8; 32];
+        loop {
+            for chunk in bytes.chunks_mut(8) {
+                chunk.copy_from_slice(&self.next_u64().to_be_bytes());
+            }
+            // Seuls les `bits` bits de poids faible sont conservés
+            let clear = 256 - bits;
+            for (i, byte) in bytes.iter_mut().enumerate().take(clear.div_ceil(8)) {
+                if (i + 1) * 8 <= clear {
+                    *byte = 0;
+                } else {
+                    *byte &= 0xff >> (clear % 8);
+                }
+            }
+            let value = U256::from_be_bytes(&bytes);
+            if value < *bound { return value; }
+        }
+    }
+}
+
+impl RngCore for FastRng {
+    fn next_u32(&mut self) -> u32 {
+        (FastRng::next_u64(self) >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        FastRng::next_u64(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = FastRng::next_u64(self).to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(config: &Config, core_id: usize) -> Vec<U256> {
+        let mut strategy = build_strategy(config, core_id, ubig!(1000), ubig!(0xffffff)).unwrap();
+        let mut batch = Vec::new();
+        for _ in 0..8 {
+            strategy.next_batch(&mut batch);
+        }
+        batch
+    }
+
+    #[test]
+    fn same_seed_replays_the_same_candidates() {
+        for mode in [SearchMode::Sequential, SearchMode::Random, SearchMode::Smart, SearchMode::Stride, SearchMode::Kangaroo, SearchMode::Shuffle] {
+            let config = Config { mode, seed: Some(42), switch_interval: 100, stride: ubig!(7), ..Config::default() };
+            assert_eq!(candidates(&config, 0), candidates(&config, 0), "mode {}", mode);
+            if mode != SearchMode::Sequential && mode != SearchMode::Stride {
+                assert_ne!(candidates(&config, 0), candidates(&config, 1), "mode {}", mode);
+                let other = Config { seed: Some(43), ..config.clone() };
+                assert_ne!(candidates(&config, 0), candidates(&other, 0), "mode {}", mode);
+            }
+        }
+    }
+
+    // Khi-deux des effectifs observés contre une répartition uniforme
+    fn chi_square(counts: &[u32]) -> f64 {
+        let expected = counts.iter().sum::<u32>() as f64 / counts.len() as f64;
+        counts.iter().map(|&c| (c as f64 - expected).powi(2) / expected).sum()
+    }
+
+    #[test]
+    fn gen_below_stays_below_bound() {
+        let mut rng = FastRng::new(1);
+        let bounds = [ubig!(2), ubig!(3), ubig!(1) << 64, (ubig!(1) << 64) + ubig!(1), (ubig!(1) << 256) - ubig!(1), ubig!(1) << 256];
+        for bound in &bounds {
+            for _ in 0..2000 {
+                assert!(rng.gen_below(bound) < *bound);
+            }
+        }
+        assert_eq!(rng.gen_below(&ubig!(1)), ubig!(0));
+        assert_eq!(rng.gen_below(&ubig!(0)), ubig!(0));
+    }
+
+    #[test]
+    fn gen_below_is_uniform_on_small_and_power_of_two_ranges() {
+        // Seuils à 99,9 % : 15 degrés de liberté (au plus) -> 37,7 ; 63 -> 103,4
+        let mut rng = FastRng::new(2);
+        for bound in [16u32, 3 * 5] {
+            let mut counts = vec![0u32; bound as usize];
+            for _ in 0..bound * 2000 {
+                counts[usize::try_from(rng.gen_below(&UBig::from(bound))).unwrap()] += 1;
+            }
+            assert!(chi_square(&counts) < 37.7, "plage {} : khi-deux = {}", bound, chi_square(&counts));
+        }
+
+        // Bits de poids faible sur une plage de 2^20 : chaque résidu modulo 64 est atteint uniformément
+        let mut counts = [0u32; 64];
+        for _ in 0..64_000 {
+            counts[usize::try_from(rng.gen_below(&(ubig!(1) << 20)) % 64u32).unwrap()] += 1;
+        }
+        assert!(chi_square(&counts) < 103.4, "khi-deux = {}", chi_square(&counts));
+    }
+
+    #[test]
+    fn gen_below_is_uniform_over_256_bit_ranges() {
+        // Juste au-dessus de 2^255 : un tirage naïf modulo la borne surreprésenterait le bas de la plage
+        let bound = (ubig!(1) << 255) + ubig!(1);
+        let mut rng = FastRng::new(3);
+        let mut counts = [0u32; 16];
+        for _ in 0..16_000 {
+            counts[usize::try_from(rng.gen_below(&bound) * ubig!(16) / &bound).unwrap()] += 1;
+        }
+        assert!(chi_square(&counts) < 37.7, "khi-deux = {}", chi_square(&counts));
+    }
+
+    #[test]
+    fn gen_below_key_is_uniform_and_below_bound() {
+        let mut rng = FastRng::new(4);
+        for bound in [ubig!(2), ubig!(1) << 64, (ubig!(1) << 255) + ubig!(1), (ubig!(1) << 256) - ubig!(1)] {
+            let key_bound = U256::try_from(&bound).unwrap();
+            for _ in 0..2000 {
+                assert!(rng.gen_below_key(&key_bound) < key_bound);
+            }
+        }
+        assert_eq!(rng.gen_below_key(&U256::ONE), U256::ZERO);
+
+        let mut counts = [0u32; 15];
+        for _ in 0..15 * 2000 {
+            counts[rng.gen_below_key(&U256::from(15)).low_u64() as usize] += 1;
+        }
+        assert!(chi_square(&counts) < 37.7, "khi-deux = {}", chi_square(&counts));
+    }
+
+    #[test]
+    fn rng_state_round_trips() {
+        let mut rng = FastRng::new(9);
+        rng.next_u64();
+        let mut copy = FastRng::from_state(rng.state()).unwrap();
+        assert_eq!(rng.next_u64(), copy.next_u64());
+        assert!(FastRng::from_state([0; 4]).is_none());
+    }
+
+    #[test]
+    fn worker_seeds_are_distinct() {
+        let seeds: std::collections::HashSet<u64> = (0..64)
+            .flat_map(|id| [worker_seed(7, id), worker_seed(7, GPU_STREAM + id)])
+            .collect();
+        assert_eq!(seeds.len(), 128);
+    }
+}
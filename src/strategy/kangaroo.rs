@@ -0,0 +1,104 @@
+// Marche "kangourou" : sauts de tailles puissances de deux, choisies de façon déterministe à partir
+// de la position courante et du nombre de pas, avec retour au début de la plage. Avec des cibles de type
+// adresse il n'y a pas de collision exploitable : c'est une exploration par sauts à pas moyen ~ sqrt(plage).
+use ibig::{ubig, UBig};
+use serde_json::json;
+
+use super::{hex, state_field, FastRng, SearchMode, SearchStrategy, CHUNK_SIZE};
+use crate::u256::U256;
+
+pub struct KangarooStrategy {
+    start: U256,
+    range: U256,
+    offset: U256,
+    jumps: Vec<U256>,
+    steps: u64,
+}
+
+impl KangarooStrategy {
+    pub fn new(start: UBig, end: UBig, mut rng: FastRng) -> Self {
+        let range = &end - &start + ubig!(1);
+        let jump_count = (range.bit_len() / 2).max(1);
+        let jumps = (0..jump_count).map(|i| U256::saturating_from(&(ubig!(1) << i))).collect();
+        let offset = U256::saturating_from(&rng.gen_below(&range));
+        Self { start: U256::saturating_from(&start), range: U256::saturating_from(&range), offset, jumps, steps: 0 }
+    }
+
+    // Indice du saut dérivé des 64 bits de poids faible de la position et du compteur de pas
+    // (mélange splitmix64). Le compteur évite que la marche ne boucle indéfiniment sur un cycle.
+    fn jump_index(&self) -> usize {
+        let low = self.offset.low_u64();
+        let mut z = (low ^ self.steps.wrapping_mul(0xd1b5_4a32_d192_ed03)).wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        (z % self.jumps.len() as u64) as usize
+    }
+}
+
+impl SearchStrategy for KangarooStrategy {
+    fn mode(&self) -> SearchMode {
+        SearchMode::Kangaroo
+    }
+
+    fn next_batch(&mut self, batch: &mut Vec<U256>) -> bool {
+        let limit = batch.len() + CHUNK_SIZE;
+        while batch.len() < limit {
+            batch.push(self.start + self.offset);
+            // offset + saut modulo la plage, sans dépasser 256 bits
+            let jump = self.jumps[self.jump_index()];
+            let room = self.range - jump;
+            self.offset = if self.offset >= room { self.offset - room } else { self.offset + jump };
+            self.steps += 1;
+        }
+        true
+    }
+
+    fn position(&self) -> UBig {
+        (self.start + self.offset).into()
+    }
+
+    fn state(&self) -> serde_json::Value {
+        json!({ "position": hex(&self.position()), "steps": self.steps })
+    }
+
+    fn restore(&mut self, state: &serde_json::Value) -> Result<(), String> {
+        let position = state_field(state, "position")?.ok_or("Point de contrôle sans position")?;
+        let start = UBig::from(self.start);
+        if position < start || position >= &start + UBig::from(self.range) {
+            return Err(format!("Position {} hors de la plage", hex(&position)));
+        }
+        self.offset = U256::saturating_from(&(position - start));
+        self.steps = state.get("steps").and_then(|v| v.as_u64()).unwrap_or(0);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walk_is_deterministic_and_stays_in_range() {
+        let mut strategy = KangarooStrategy::new(ubig!(0x8000), ubig!(0xffff), FastRng::new(11));
+        let mut keys = Vec::new();
+        for _ in 0..8 {
+            strategy.next_batch(&mut keys);
+        }
+        assert!(keys.iter().all(|key| *key >= U256::from(0x8000) && *key <= U256::from(0xffff)));
+        // Sauts entre 1 et 2^7 pour une plage de 2^15 clés
+        let max_jump = keys.windows(2)
+            .filter(|pair| pair[1] > pair[0])
+            .map(|pair| pair[1] - pair[0])
+            .max()
+            .unwrap();
+        assert!(max_jump <= U256::from(128));
+
+        let mut resumed = KangarooStrategy::new(ubig!(0x8000), ubig!(0xffff), FastRng::new(12));
+        resumed.restore(&strategy.state()).unwrap();
+        let (mut a, mut b) = (Vec::new(), Vec::new());
+        strategy.next_batch(&mut a);
+        resumed.next_batch(&mut b);
+        assert_eq!(a, b);
+    }
+}
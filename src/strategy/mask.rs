@@ -0,0 +1,271 @@
+// Recherche par masque : clés dont certains bits sont connus. Le masque s'écrit en hexadécimal avec
+// des jokers (`0x3ff??????????a1??`, chaque `?` = 4 bits libres) ou sous la forme valeur/masque des bits
+// connus (`0x3ff0000000000a100/0xfff0000000000ff00`). Seules les clés compatibles sont énumérées, dans
+// l'ordre croissant : la clé d'indice i place les bits de i dans les positions libres.
+use ibig::{ubig, UBig};
+use serde_json::json;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{hex, state_field, SearchMode, SearchStrategy, CHUNK_SIZE};
+use crate::config::parse_big_int;
+use crate::u256::U256;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyMask {
+    source: String,
+    // Bits connus (à 0 hors de `known`)
+    value: UBig,
+    // Masque des bits connus sous la largeur du masque (les bits au-delà sont nuls)
+    known: UBig,
+    // Masque et positions (croissantes) des bits libres
+    free: UBig,
+    positions: Vec<usize>,
+}
+
+impl KeyMask {
+    fn new(source: &str, value: UBig, width: usize, free: UBig) -> Result<Self, String> {
+        if width > 256 {
+            return Err(format!("Masque trop long ({} bits, 256 au plus): {}", width, source));
+        }
+        let known = (ubig!(1) << width) - ubig!(1) - &free;
+        let positions = (0..width).filter(|&bit| free.bit(bit)).collect();
+        Ok(Self { source: source.to_string(), value, known, free, positions })
+    }
+
+    // Nombre de bits libres
+    pub fn free_bits(&self) -> usize {
+        self.positions.len()
+    }
+
+    // Nombre total de clés compatibles avec le masque (2^bits libres)
+    pub fn len(&self) -> UBig {
+        ubig!(1) << self.free_bits()
+    }
+
+    // Clé d'indice `index` (< len) : bits de l'indice déposés dans les positions libres
+    pub fn key_at(&self, index: &UBig) -> UBig {
+        let mut key = self.value.clone();
+        for (i, &position) in self.positions.iter().enumerate() {
+            if index.bit(i) {
+                key.set_bit(position);
+            }
+        }
+        key
+    }
+
+    // Plus petit indice dont la clé est >= key (len si aucune) ; les clés croissent avec l'indice
+    pub fn index_at_least(&self, key: &UBig) -> UBig {
+        let (mut low, mut high) = (ubig!(0), self.len());
+        while low < high {
+            let middle = (&low + &high) >> 1;
+            if self.key_at(&middle) < *key {
+                low = middle + ubig!(1);
+            } else {
+                high = middle;
+            }
+        }
+        low
+    }
+
+    // Indices [first, last) des clés compatibles comprises dans [start, end]
+    pub fn index_range(&self, start: &UBig, end: &UBig) -> (UBig, UBig) {
+        (self.index_at_least(start), self.index_at_least(&(end + ubig!(1))))
+    }
+
+    // Nombre de clés compatibles dans [start, end] : le volume de travail réel de la recherche
+    pub fn count_in(&self, start: &UBig, end: &UBig) -> UBig {
+        let (first, last) = self.index_range(start, end);
+        if last > first { last - first } else { ubig!(0) }
+    }
+
+}
+
+// Masque sur 256 bits pour l'énumération clé par clé
+#[derive(Debug, Clone, Copy)]
+struct KeyBits {
+    value: U256,
+    known: U256,
+    free: U256,
+}
+
+impl KeyBits {
+    fn new(mask: &KeyMask) -> Self {
+        let bits = |value: &UBig| U256::try_from(value).expect("masque de 256 bits au plus");
+        Self { value: bits(&mask.value), known: bits(&mask.known), free: bits(&mask.free) }
+    }
+
+    // Clé suivante : la retenue traverse les bits connus et ne s'arrête que sur un bit libre
+    // (après la dernière clé d'un masque de 256 bits, la retenue sort et l'on repart de la première)
+    fn next_key(&self, key: U256) -> U256 {
+        let free_part = (key & self.free).wrapping_add(&self.known).wrapping_add(&U256::ONE) & self.free;
+        self.value | free_part
+    }
+}
+
+impl fmt::Display for KeyMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl FromStr for KeyMask {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some((value, known)) = s.split_once('/') {
+            let value = parse_big_int(value.trim()).map_err(|e| format!("Valeur du masque invalide: {} ({})", value, e))?;
+            let known = parse_big_int(known.trim()).map_err(|e| format!("Masque invalide: {} ({})", known, e))?;
+            if &value & &known != value {
+                return Err(format!("La valeur {} a des bits hors du masque des bits connus", hex(&value)));
+            }
+            let width = known.bit_len();
+            let free = (ubig!(1) << width) - ubig!(1) - &known;
+            return Self::new(s, value, width, free);
+        }
+
+        let digits = s.strip_prefix("0x").unwrap_or(s);
+        if digits.is_empty() {
+            return Err("Masque vide".to_string());
+        }
+        let (mut value, mut free) = (ubig!(0), ubig!(0));
+        for c in digits.chars() {
+            value <<= 4;
+            free <<= 4;
+            match c {
+                '?' => free |= ubig!(0xf),
+                _ => value |= UBig::from(c.to_digit(16).ok_or_else(|| format!("Caractère invalide '{}' dans le masque {}", c, s))?),
+            }
+        }
+        Self::new(s, value, digits.len() * 4, free)
+    }
+}
+
+pub struct MaskStrategy {
+    mask: KeyMask,
+    bits: KeyBits,
+    end: UBig,
+    // Indice de la prochaine clé et fin (exclue) des indices de la plage
+    index: U256,
+    last: U256,
+    current: U256,
+}
+
+impl MaskStrategy {
+    pub fn new(mask: KeyMask, start: UBig, end: UBig) -> Self {
+        let (index, last) = mask.index_range(&start, &end);
+        let current = U256::saturating_from(&mask.key_at(&index));
+        let (index, last) = (U256::saturating_from(&index), U256::saturating_from(&last));
+        Self { bits: KeyBits::new(&mask), mask, end, index, last, current }
+    }
+}
+
+impl SearchStrategy for MaskStrategy {
+    fn mode(&self) -> SearchMode {
+        SearchMode::Mask
+    }
+
+    fn next_batch(&mut self, batch: &mut Vec<U256>) -> bool {
+        if self.index >= self.last {
+            return false;
+        }
+        let limit = batch.len() + CHUNK_SIZE;
+        while batch.len() < limit && self.index < self.last {
+            batch.push(self.current);
+            self.current = self.bits.next_key(self.current);
+            self.index += U256::ONE;
+        }
+        true
+    }
+
+    fn position(&self) -> UBig {
+        if self.index < self.last { self.current.into() } else { &self.end + ubig!(1) }
+    }
+
+    fn parameters(&self) -> serde_json::Value {
+        json!({ "mask": self.mask.to_string() })
+    }
+
+    fn state(&self) -> serde_json::Value {
+        json!({ "position": hex(&self.position()), "index": hex(&self.index.into()) })
+    }
+
+    // L'indice fait foi ; à défaut (ancien point de contrôle) on reprend à la première clé >= position
+    fn restore(&mut self, state: &serde_json::Value) -> Result<(), String> {
+        let index = match state_field(state, "index")? {
+            Some(index) => index,
+            None => {
+                let position = state_field(state, "position")?.ok_or("Point de contrôle sans position")?;
+                self.mask.index_at_least(&position)
+            }
+        };
+        if index > UBig::from(self.last) {
+            return Err(format!("Indice {} hors de la plage", hex(&index)));
+        }
+        self.current = U256::saturating_from(&self.mask.key_at(&index));
+        self.index = U256::saturating_from(&index);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mask(s: &str) -> KeyMask {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_wildcards_and_value_mask_pairs() {
+        let wildcards = mask("0x3f?a?");
+        assert_eq!(wildcards.free_bits(), 8);
+        assert_eq!(wildcards.key_at(&ubig!(0)), ubig!(0x3f0a0));
+        assert_eq!(wildcards.key_at(&ubig!(0xff)), ubig!(0x3ffaf));
+
+        let pair = mask("0x3f0a0/0xff0f0");
+        assert_eq!(pair.free_bits(), 8);
+        assert_eq!((0..256u32).map(|i| pair.key_at(&UBig::from(i))).collect::<Vec<_>>(),
+                   (0..256u32).map(|i| wildcards.key_at(&UBig::from(i))).collect::<Vec<_>>());
+
+        assert!("0x3g?".parse::<KeyMask>().is_err());
+        assert!("0x3f/0x10".parse::<KeyMask>().is_err());
+        assert!(format!("0x{}", "?".repeat(65)).parse::<KeyMask>().is_err());
+    }
+
+    #[test]
+    fn enumerates_only_compatible_keys_in_range() {
+        let mask = mask("0x1?5?");
+        let (start, end) = (ubig!(0x1250), ubig!(0x1a5f));
+        let mut strategy = MaskStrategy::new(mask.clone(), start.clone(), end.clone());
+        let mut keys = Vec::new();
+        while strategy.next_batch(&mut keys) {}
+
+        let expected: Vec<U256> = (0x1000u64..=0x1fff)
+            .filter(|&key| (0x1250..=0x1a5f).contains(&key) && (key >> 4) % 16 == 5 && (key >> 12) == 1)
+            .map(U256::from)
+            .collect();
+        assert_eq!(keys, expected);
+        assert_eq!(mask.count_in(&start, &end), UBig::from(expected.len()));
+        assert_eq!(mask.count_in(&ubig!(0x2000), &ubig!(0x3000)), ubig!(0));
+    }
+
+    #[test]
+    fn resumes_from_index_or_legacy_position() {
+        let mask = mask("0x8?????????????????????????????????????????????????????????????1");
+        let start = mask.key_at(&ubig!(0));
+        let end = mask.key_at(&(mask.len() - ubig!(1)));
+        let mut strategy = MaskStrategy::new(mask.clone(), start.clone(), end.clone());
+        let mut batch = Vec::new();
+        strategy.next_batch(&mut batch);
+        assert_eq!(strategy.state()["index"], hex(&UBig::from(CHUNK_SIZE)));
+
+        let mut resumed = MaskStrategy::new(mask.clone(), start.clone(), end.clone());
+        resumed.restore(&strategy.state()).unwrap();
+        assert_eq!(resumed.position(), strategy.position());
+
+        resumed.restore(&json!({ "position": hex(&(UBig::from(batch[3]) + ubig!(1))) })).unwrap();
+        assert_eq!(resumed.position(), UBig::from(batch[4]));
+    }
+}
@@ -0,0 +1,188 @@
+// Familles de patterns du mode "smart" : clés candidates dérivées d'une clé de base.
+// Chaque famille est activable séparément (`smart_patterns`) et mesurée dans les benchmarks.
+// Les candidats qui dépasseraient 256 bits sont hors de toute plage et ne sont pas produits.
+use rand::seq::SliceRandom;
+use std::fmt;
+use std::str::FromStr;
+
+use super::FastRng;
+use crate::u256::U256;
+
+// Décalages des voisins (suite de Fibonacci)
+const NEIGHBOUR_OFFSETS: [u64; 10] = [1, 2, 3, 5, 8, 13, 21, 34, 55, 89];
+// Facteurs premiers des multiples
+const FACTORS: [u64; 6] = [2, 3, 5, 7, 11, 13];
+// Nombre de permutations aléatoires des chiffres
+const SHUFFLES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatternFamily {
+    // base ± 1, 2, 3, 5, ..., 89
+    Neighbours,
+    // base × 2, 3, 5, 7, 11, 13
+    Multiples,
+    // Chiffres décimaux inversés
+    Reversed,
+    // Chiffres décimaux mélangés
+    Shuffled,
+}
+
+impl PatternFamily {
+    pub const ALL: [PatternFamily; 4] = [
+        PatternFamily::Neighbours,
+        PatternFamily::Multiples,
+        PatternFamily::Reversed,
+        PatternFamily::Shuffled,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PatternFamily::Neighbours => "neighbours",
+            PatternFamily::Multiples => "multiples",
+            PatternFamily::Reversed => "reversed",
+            PatternFamily::Shuffled => "shuffled",
+        }
+    }
+
+    // Ajoute à `out` les candidats de la famille pour `base` (sans filtrage de plage)
+    pub fn generate(&self, base: &U256, rng: &mut FastRng, out: &mut Vec<U256>) {
+        match self {
+            PatternFamily::Neighbours => {
+                for &offset in &NEIGHBOUR_OFFSETS {
+                    let offset = U256::from(offset);
+                    out.extend(base.checked_add(&offset));
+                    if *base > offset {
+                        out.push(*base - offset);
+                    }
+                }
+            },
+            PatternFamily::Multiples => out.extend(FACTORS.iter().filter_map(|&factor| base.checked_mul_u64(factor))),
+            PatternFamily::Reversed => {
+                let reversed: String = base.to_string().chars().rev().collect();
+                if let Ok(value) = U256::from_str(&reversed) {
+                    out.push(value);
+                }
+            },
+            PatternFamily::Shuffled => {
+                let mut digits: Vec<char> = base.to_string().chars().collect();
+                for _ in 0..SHUFFLES.min(digits.len().saturating_sub(1)) {
+                    digits.shuffle(rng);
+                    if let Ok(value) = U256::from_str(&digits.iter().collect::<String>()) {
+                        out.push(value);
+                    }
+                }
+            },
+        }
+    }
+}
+
+impl fmt::Display for PatternFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PatternFamily {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "neighbours" | "neighbors" => Ok(PatternFamily::Neighbours),
+            "multiples" => Ok(PatternFamily::Multiples),
+            "reversed" => Ok(PatternFamily::Reversed),
+            "shuffled" => Ok(PatternFamily::Shuffled),
+            _ => Err(format!("Famille de patterns inconnue: {}. Utilisez 'neighbours', 'multiples', 'reversed' ou 'shuffled'", s)),
+        }
+    }
+}
+
+// Liste séparée par des virgules ("neighbours,reversed") ; vide = aucune famille
+pub fn parse_pattern_families(value: &str) -> Result<Vec<PatternFamily>, String> {
+    let mut families = Vec::new();
+    for name in value.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+        let family = PatternFamily::from_str(name)?;
+        if !families.contains(&family) {
+            families.push(family);
+        }
+    }
+    Ok(families)
+}
+
+// Candidats des familles données pour `base_key`, limités à la plage [start, end], triés et sans doublon
+pub fn generate_key_patterns(base_key: &U256, families: &[PatternFamily], (start, end): (&U256, &U256), rng: &mut FastRng) -> Vec<U256> {
+    let mut patterns = Vec::new();
+    for family in families {
+        family.generate(base_key, rng, &mut patterns);
+    }
+    patterns.retain(|key| key >= start && key <= end);
+    patterns.sort();
+    patterns.dedup();
+    patterns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_family_generates_its_own_candidates() {
+        let base = U256::from(1230);
+        let mut rng = FastRng::new(1);
+        let generate = |family: PatternFamily, rng: &mut FastRng| {
+            let mut out = Vec::new();
+            family.generate(&base, rng, &mut out);
+            out
+        };
+
+        let neighbours = generate(PatternFamily::Neighbours, &mut rng);
+        assert_eq!(neighbours.len(), 20);
+        assert!(neighbours.contains(&U256::from(1319)) && neighbours.contains(&U256::from(1141)));
+        assert_eq!(generate(PatternFamily::Multiples, &mut rng), [2460, 3690, 6150, 8610, 13530, 15990].map(U256::from));
+        assert_eq!(generate(PatternFamily::Reversed, &mut rng), [U256::from(321)]);
+        let shuffled = generate(PatternFamily::Shuffled, &mut rng);
+        assert_eq!(shuffled.len(), 3);
+        assert!(shuffled.iter().all(|key| {
+            let mut digits: Vec<char> = key.to_string().chars().collect();
+            digits.sort();
+            digits.iter().collect::<String>().trim_start_matches('0') == "123"
+        }));
+    }
+
+    #[test]
+    fn patterns_stay_in_range_without_duplicates() {
+        let (start, end) = (U256::from(1000), U256::from(1100));
+        let patterns = generate_key_patterns(&U256::from(1010), &PatternFamily::ALL, (&start, &end), &mut FastRng::new(2));
+        assert!(!patterns.is_empty());
+        assert!(patterns.iter().all(|key| *key >= start && *key <= end));
+        assert!(patterns.windows(2).all(|pair| pair[0] < pair[1]));
+        // Les multiples (2020, ...) et l'inversion (101) sortent de la plage
+        assert!(!patterns.contains(&U256::from(2020)) && !patterns.contains(&U256::from(101)));
+    }
+
+    #[test]
+    fn candidates_beyond_256_bits_are_dropped() {
+        let mut out = Vec::new();
+        PatternFamily::Multiples.generate(&U256::MAX, &mut FastRng::new(1), &mut out);
+        assert!(out.is_empty());
+        PatternFamily::Neighbours.generate(&U256::MAX, &mut FastRng::new(1), &mut out);
+        assert_eq!(out.len(), NEIGHBOUR_OFFSETS.len());
+        assert!(out.iter().all(|key| *key < U256::MAX));
+    }
+
+    #[test]
+    fn parses_family_lists() {
+        assert_eq!(parse_pattern_families("neighbours, reversed,neighbours").unwrap(), [PatternFamily::Neighbours, PatternFamily::Reversed]);
+        assert_eq!(parse_pattern_families("").unwrap(), []);
+        assert!(parse_pattern_families("neighbours,primes").is_err());
+    }
+
+    #[test]
+    fn key_patterns_depend_only_on_rng_state() {
+        let base = U256::from(123456789);
+        let range = (&U256::from(1), &U256::from(999_999_999));
+        assert_eq!(
+            generate_key_patterns(&base, &PatternFamily::ALL, range, &mut FastRng::new(1)),
+            generate_key_patterns(&base, &PatternFamily::ALL, range, &mut FastRng::new(1))
+        );
+    }
+}
@@ -0,0 +1,396 @@
+// Parcours aléatoire : courtes séquences contiguës, puis saut vers un point tiré uniformément
+// dans la plage après `switch_interval` clés.
+// Avec `subinterval_ratio`, chaque saut choisit un sous-intervalle de cette fraction de la plage, qui est
+// parcouru en entier puis enregistré comme couvert : les sauts suivants ne tirent que parmi les clés non
+// couvertes, et le parcours se termine une fois toute la plage couverte.
+use ibig::{ubig, UBig};
+use serde_json::json;
+
+use super::{hex, rng_field, state_field, FastRng, SearchMode, SearchStrategy, CHUNK_SIZE};
+use crate::config::parse_big_int;
+use crate::u256::U256;
+
+// Clés des sous-intervalles déjà parcourus : intervalles [début, fin] disjoints, triés et non adjacents
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct CoveredKeys {
+    intervals: Vec<(UBig, UBig)>,
+    total: UBig,
+}
+
+impl CoveredKeys {
+    // Ajoute [start, end], disjoint des intervalles déjà couverts, en fusionnant avec ses voisins
+    fn insert(&mut self, start: UBig, end: UBig) {
+        self.total += &end - &start + ubig!(1);
+        let mut index = self.intervals.partition_point(|(_, covered_end)| *covered_end < start);
+        let mut interval = (start, end);
+        if index > 0 && &self.intervals[index - 1].1 + ubig!(1) == interval.0 {
+            interval.0 = self.intervals.remove(index - 1).0;
+            index -= 1;
+        }
+        if index < self.intervals.len() && &interval.1 + ubig!(1) == self.intervals[index].0 {
+            interval.1 = self.intervals.remove(index).1;
+        }
+        self.intervals.insert(index, interval);
+    }
+
+    // Clé non couverte d'indice `index` dans [start, end], et dernière clé de la zone libre qui la contient
+    fn uncovered_at(&self, start: &UBig, end: &UBig, mut index: UBig) -> (UBig, UBig) {
+        let mut gap_start = start.clone();
+        for (covered_start, covered_end) in &self.intervals {
+            let gap = covered_start - &gap_start;
+            if index < gap {
+                return (gap_start + index, covered_start - ubig!(1));
+            }
+            index -= gap;
+            gap_start = covered_end + ubig!(1);
+        }
+        (gap_start + index, end.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        self.intervals.iter().map(|(start, end)| json!([hex(start), hex(end)])).collect()
+    }
+
+    // Intervalles d'un point de contrôle, qui doivent rester triés, disjoints et dans [start, end]
+    fn from_json(value: &serde_json::Value, start: &UBig, end: &UBig) -> Result<Self, String> {
+        let invalid = || "Point de contrôle invalide (covered)".to_string();
+        let mut covered = Self::default();
+        for interval in value.as_array().ok_or_else(invalid)? {
+            let bounds: Vec<UBig> = interval
+                .as_array()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(|bound| bound.as_str().and_then(|bound| parse_big_int(bound).ok()))
+                .collect::<Option<_>>()
+                .ok_or_else(invalid)?;
+            let [low, high] = <[UBig; 2]>::try_from(bounds).map_err(|_| invalid())?;
+            let after_previous = covered.intervals.last().is_none_or(|(_, previous)| low > *previous);
+            if !after_previous || low < *start || low > high || high > *end {
+                return Err(invalid());
+            }
+            covered.insert(low, high);
+        }
+        Ok(covered)
+    }
+}
+
+pub struct RandomStrategy {
+    start: UBig,
+    end: UBig,
+    // Clé courante et fin de la plage sur 256 bits pour la boucle par clé
+    current: U256,
+    last: U256,
+    switch_interval: u64,
+    since_switch: u64,
+    // Longueur des sous-intervalles parcourus en entier (None : saut après `switch_interval` clés)
+    subinterval: Option<UBig>,
+    // Sous-intervalle en cours et clés des sous-intervalles terminés
+    scan_start: U256,
+    scan_end: U256,
+    covered: CoveredKeys,
+    finished: bool,
+    rng: FastRng,
+}
+
+impl RandomStrategy {
+    pub fn new(start: UBig, end: UBig, switch_interval: u64, rng: FastRng) -> Self {
+        let first = U256::saturating_from(&start);
+        let mut strategy = Self {
+            current: first,
+            last: U256::saturating_from(&end),
+            scan_start: first,
+            scan_end: first,
+            start,
+            end,
+            switch_interval: switch_interval.max(1),
+            since_switch: 0,
+            subinterval: None,
+            covered: CoveredKeys::default(),
+            finished: false,
+            rng,
+        };
+        strategy.jump();
+        strategy
+    }
+
+    // Parcours par sous-intervalles de `ratio` × la plage (au moins une clé) ; 0 conserve les sauts
+    // après `switch_interval` clés
+    pub fn with_subinterval_ratio(mut self, ratio: f64) -> Self {
+        if ratio > 0.0 {
+            // Ratio en virgule fixe sur 32 bits : la longueur reste exacte sur une plage de 256 bits
+            let fixed = UBig::from((ratio.min(1.0) * 4_294_967_296.0) as u64);
+            let length = ((&self.end - &self.start + ubig!(1)) * fixed) >> 32;
+            self.subinterval = Some(length.max(ubig!(1)));
+            self.jump();
+        }
+        self
+    }
+
+    pub fn current(&self) -> &U256 {
+        &self.current
+    }
+
+    // Toute la plage a été couverte par sous-intervalles
+    pub fn finished(&self) -> bool {
+        self.finished
+    }
+
+    fn jump(&mut self) {
+        let range = &self.end - &self.start + ubig!(1);
+        self.since_switch = 0;
+        let Some(length) = &self.subinterval else {
+            self.current = U256::saturating_from(&(&self.start + self.rng.gen_below(&range)));
+            return;
+        };
+
+        // Début tiré uniformément parmi les clés non couvertes ; le sous-intervalle s'arrête
+        // avant la prochaine zone couverte
+        let uncovered = range - &self.covered.total;
+        if uncovered == ubig!(0) {
+            self.finished = true;
+            return;
+        }
+        let (key, gap_end) = self.covered.uncovered_at(&self.start, &self.end, self.rng.gen_below(&uncovered));
+        self.scan_end = U256::saturating_from(&(&key + length - ubig!(1)).min(gap_end));
+        self.scan_start = U256::saturating_from(&key);
+        self.current = self.scan_start;
+    }
+
+    // Avance après `checked` clés : clé suivante, ou saut si l'intervalle est atteint ou la fin dépassée.
+    // Par sous-intervalles, chaque appel avance d'une clé jusqu'à la fin du sous-intervalle.
+    pub fn advance(&mut self, checked: u64) {
+        if self.subinterval.is_some() {
+            if self.current < self.scan_end {
+                self.current += U256::ONE;
+            } else {
+                self.covered.insert(self.scan_start.into(), self.scan_end.into());
+                self.jump();
+            }
+            return;
+        }
+        self.since_switch += checked;
+        if self.since_switch >= self.switch_interval || self.current >= self.last {
+            self.jump();
+        } else {
+            self.current += U256::ONE;
+        }
+    }
+}
+
+impl SearchStrategy for RandomStrategy {
+    fn mode(&self) -> SearchMode {
+        SearchMode::Random
+    }
+
+    fn next_batch(&mut self, batch: &mut Vec<U256>) -> bool {
+        if self.finished {
+            return false;
+        }
+        let limit = batch.len() + CHUNK_SIZE;
+        while batch.len() < limit && !self.finished {
+            batch.push(self.current);
+            self.advance(1);
+        }
+        true
+    }
+
+    fn position(&self) -> UBig {
+        if self.finished { &self.end + ubig!(1) } else { self.current.into() }
+    }
+
+    fn state(&self) -> serde_json::Value {
+        let mut state = json!({
+            "position": hex(&self.position()),
+            "since_switch": self.since_switch,
+            "rng": self.rng.state(),
+        });
+        if self.subinterval.is_some() {
+            state["subinterval"] = json!([hex(&self.scan_start.into()), hex(&self.scan_end.into())]);
+            state["covered"] = self.covered.to_json();
+        }
+        state
+    }
+
+    fn restore(&mut self, state: &serde_json::Value) -> Result<(), String> {
+        let position = state_field(state, "position")?.ok_or("Point de contrôle sans position")?;
+        let finished = self.subinterval.is_some() && position == &self.end + ubig!(1);
+        if position < self.start || (position > self.end && !finished) {
+            return Err(format!("Position {} hors de la plage", hex(&position)));
+        }
+        // Par sous-intervalles, un point de contrôle sans couverture (ancien format, ou enregistré
+        // sans `subinterval_ratio`) reprend un sous-intervalle à la position enregistrée
+        let scan = match (&self.subinterval, state.get("covered")) {
+            (None, _) => None,
+            (Some(_), Some(covered)) => {
+                let covered = CoveredKeys::from_json(covered, &self.start, &self.end)?;
+                let bounds = state.get("subinterval").and_then(|v| v.as_array()).map(|bounds| {
+                    bounds.iter().filter_map(|bound| bound.as_str().and_then(|bound| parse_big_int(bound).ok())).collect::<Vec<_>>()
+                });
+                let (scan_start, scan_end) = match bounds.map(<[UBig; 2]>::try_from) {
+                    Some(Ok([scan_start, scan_end])) => (scan_start, scan_end),
+                    _ => return Err("Point de contrôle invalide (subinterval)".to_string()),
+                };
+                if !finished && (position < scan_start || position > scan_end) {
+                    return Err(format!("Position {} hors du sous-intervalle en cours", hex(&position)));
+                }
+                Some((scan_start, scan_end, covered))
+            },
+            (Some(length), None) => {
+                let scan_end = (&position + length - ubig!(1)).min(self.end.clone());
+                Some((position.clone(), scan_end, CoveredKeys::default()))
+            },
+        };
+        // Un état `rng` d'un seul entier (ancien générateur congruentiel) n'est pas repris :
+        // le parcours continue avec le générateur courant
+        let rng = rng_field(state, "rng")?;
+        if let Some((scan_start, scan_end, covered)) = scan {
+            self.scan_start = U256::saturating_from(&scan_start);
+            self.scan_end = U256::saturating_from(&scan_end);
+            self.covered = covered;
+        }
+        self.finished = finished;
+        self.current = U256::saturating_from(&position);
+        self.since_switch = state.get("since_switch").and_then(|v| v.as_u64()).unwrap_or(0);
+        if let Some(rng) = rng {
+            self.rng = rng;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stays_in_range_and_jumps_after_interval() {
+        let mut strategy = RandomStrategy::new(ubig!(1000), ubig!(1999), 10, FastRng::new(7));
+        let mut keys = Vec::new();
+        for _ in 0..20 {
+            strategy.next_batch(&mut keys);
+        }
+
+        assert!(keys.iter().all(|key| *key >= U256::from(1000) && *key <= U256::from(1999)));
+        // Au plus `switch_interval` clés consécutives avant un saut
+        let mut run = 1;
+        for pair in keys.windows(2) {
+            run = if pair[1] == pair[0] + U256::ONE { run + 1 } else { 1 };
+            assert!(run <= 10);
+        }
+        // La plage est couverte en grande partie après ~5000 tirages
+        let mut distinct = keys.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert!(distinct.len() > 900);
+    }
+
+    #[test]
+    fn jump_targets_are_uniform_over_the_range() {
+        // Plage de 2^200 + 3 clés décalée : 32 classes de même largeur, test du khi-deux
+        let start = ubig!(0x1234_5678) << 150;
+        let end = &start + (ubig!(1) << 200) + ubig!(2);
+        let range = &end - &start + ubig!(1);
+        let mut strategy = RandomStrategy::new(start.clone(), end.clone(), 1, FastRng::new(17));
+        let mut counts = [0u32; 32];
+        for _ in 0..32_000 {
+            let key = strategy.position();
+            assert!(key >= start && key <= end);
+            let bucket = usize::try_from((key - &start) * ubig!(32) / &range).unwrap();
+            counts[bucket] += 1;
+            strategy.advance(1);
+        }
+        let chi2: f64 = counts.iter().map(|&c| (c as f64 - 1000.0).powi(2) / 1000.0).sum();
+        // Seuil à 99,9 % pour 31 degrés de liberté
+        assert!(chi2 < 61.1, "khi-deux = {}", chi2);
+    }
+
+    #[test]
+    fn ignores_legacy_rng_state() {
+        let mut strategy = RandomStrategy::new(ubig!(1), ubig!(1000), 100, FastRng::new(3));
+        let rng = strategy.rng.clone();
+        strategy.restore(&json!({ "position": "0x10", "rng": 12345 })).unwrap();
+        assert_eq!(strategy.position(), ubig!(0x10));
+        assert_eq!(strategy.rng, rng);
+        assert!(strategy.restore(&json!({ "position": "0x10", "rng": [0, 0, 0, 0] })).is_err());
+    }
+
+    #[test]
+    fn subintervals_cover_the_range_exactly_once() {
+        // 10 % de 1000 clés : des sous-intervalles d'au plus 100 clés contiguës, puis fin du parcours
+        let mut strategy = RandomStrategy::new(ubig!(1000), ubig!(1999), 10, FastRng::new(7)).with_subinterval_ratio(0.1);
+        let mut keys = Vec::new();
+        while strategy.next_batch(&mut keys) {}
+
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(sorted, (1000u64..2000).map(U256::from).collect::<Vec<_>>());
+        let mut run = 1;
+        let mut jumps = 0;
+        for pair in keys.windows(2) {
+            if pair[1] == pair[0] + U256::ONE {
+                run += 1;
+            } else {
+                run = 1;
+                jumps += 1;
+            }
+            assert!(run <= 100);
+        }
+        assert!(jumps >= 9);
+        assert_eq!(strategy.position(), ubig!(2000));
+    }
+
+    #[test]
+    fn covered_keys_merge_adjacent_intervals() {
+        let mut covered = CoveredKeys::default();
+        covered.insert(ubig!(20), ubig!(29));
+        covered.insert(ubig!(40), ubig!(49));
+        covered.insert(ubig!(30), ubig!(39));
+        covered.insert(ubig!(0), ubig!(4));
+        assert_eq!(covered.intervals, [(ubig!(0), ubig!(4)), (ubig!(20), ubig!(49))]);
+        assert_eq!(covered.total, ubig!(35));
+        // Clés non couvertes de [0, 99] : 5..=19 puis 50..=99
+        assert_eq!(covered.uncovered_at(&ubig!(0), &ubig!(99), ubig!(0)), (ubig!(5), ubig!(19)));
+        assert_eq!(covered.uncovered_at(&ubig!(0), &ubig!(99), ubig!(15)), (ubig!(50), ubig!(99)));
+    }
+
+    #[test]
+    fn resumes_subinterval_scan_from_checkpoint() {
+        let new = |seed| RandomStrategy::new(ubig!(1), ubig!(100_000), 100, FastRng::new(seed)).with_subinterval_ratio(0.01);
+        let mut strategy = new(3);
+        let mut batch = Vec::new();
+        for _ in 0..5 {
+            strategy.next_batch(&mut batch);
+        }
+        assert!(strategy.state()["covered"].as_array().is_some_and(|covered| !covered.is_empty()));
+
+        let mut resumed = new(99);
+        resumed.restore(&strategy.state()).unwrap();
+        let (mut a, mut b) = (Vec::new(), Vec::new());
+        for _ in 0..4 {
+            strategy.next_batch(&mut a);
+            resumed.next_batch(&mut b);
+        }
+        assert_eq!(a, b);
+
+        // Ancien point de contrôle : reprise d'un sous-intervalle à la position enregistrée
+        resumed.restore(&json!({ "position": "0x10" })).unwrap();
+        assert_eq!(resumed.position(), ubig!(0x10));
+        assert!(resumed.restore(&json!({ "position": "0x10", "covered": [["0x20", "0x10"]] })).is_err());
+    }
+
+    #[test]
+    fn resumes_from_checkpoint() {
+        let mut strategy = RandomStrategy::new(ubig!(1), ubig!(0x100_0000_0000), 100, FastRng::new(3));
+        let mut batch = Vec::new();
+        strategy.next_batch(&mut batch);
+
+        let mut resumed = RandomStrategy::new(ubig!(1), ubig!(0x100_0000_0000), 100, FastRng::new(99));
+        resumed.restore(&strategy.state()).unwrap();
+        let (mut a, mut b) = (Vec::new(), Vec::new());
+        for _ in 0..4 {
+            strategy.next_batch(&mut a);
+            resumed.next_batch(&mut b);
+        }
+        assert_eq!(a, b);
+    }
+}
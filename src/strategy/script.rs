@@ -0,0 +1,328 @@
+// Générateur de candidats scriptable (Rhai) pour le mode "smart" : le script définit
+//
+//     fn candidates(base, start, end) { [base + 7, key("0x1f00"), range(base - 100, base + 100)] }
+//
+// et renvoie, pour chaque clé de base, un tableau de clés (`Key`, entier ou chaîne décimale/0x)
+// et de sous-plages `range(lo, hi)`. Les candidats passent par le même filtrage de plage, la même
+// déduplication et les mêmes statistiques que les familles de patterns intégrées.
+// Bac à sable : pas d'`import` ni d'`eval`, nombre d'opérations et tailles limités, clés de 256 bits au plus.
+
+// Nombre maximal de candidats retenus par clé de base (sous-plages comprises)
+pub const MAX_SCRIPT_CANDIDATES: usize = 4096;
+
+#[cfg(feature = "scripting")]
+pub use engine::ScriptGenerator;
+
+#[cfg(feature = "scripting")]
+mod engine {
+    use ibig::{ubig, UBig};
+    use rhai::module_resolvers::DummyModuleResolver;
+    use rhai::{Array, Dynamic, Engine, EvalAltResult, Scope, AST, INT};
+
+    use super::MAX_SCRIPT_CANDIDATES;
+    use crate::config::parse_big_int;
+
+    // Opérations autorisées par appel de `candidates`
+    const MAX_OPERATIONS: u64 = 1_000_000;
+    // Taille maximale d'une clé manipulée par un script : les opérandes et les résultats plus grands sont
+    // refusés, sans quoi quelques multiplications suffiraient à épuiser la mémoire
+    const MAX_KEY_BITS: usize = 256;
+
+    type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+    // Sous-plage [lo, hi] renvoyée par `range(lo, hi)`
+    #[derive(Debug, Clone)]
+    struct KeyRange {
+        lo: UBig,
+        hi: UBig,
+    }
+
+    fn to_key(value: INT) -> ScriptResult<UBig> {
+        u64::try_from(value).map(UBig::from).map_err(|_| format!("Clé négative: {}", value).into())
+    }
+
+    fn parse_key(value: &str) -> ScriptResult<UBig> {
+        bounded(parse_big_int(value).map_err(|e| format!("Clé invalide '{}': {}", value, e))?)
+    }
+
+    fn bounded(value: UBig) -> ScriptResult<UBig> {
+        if value.bit_len() > MAX_KEY_BITS {
+            return Err(format!("Clé de plus de {} bits ({} bits)", MAX_KEY_BITS, value.bit_len()).into());
+        }
+        Ok(value)
+    }
+
+    fn add(a: UBig, b: UBig) -> ScriptResult<UBig> {
+        bounded(bounded(a)? + bounded(b)?)
+    }
+
+    fn mul(a: UBig, b: UBig) -> ScriptResult<UBig> {
+        bounded(bounded(a)? * bounded(b)?)
+    }
+
+    fn sub(a: UBig, b: UBig) -> ScriptResult<UBig> {
+        if b > a {
+            return Err(format!("Soustraction négative: {} - {}", a, b).into());
+        }
+        Ok(a - b)
+    }
+
+    fn div(a: UBig, b: UBig) -> ScriptResult<UBig> {
+        if b == ubig!(0) {
+            return Err("Division par zéro".into());
+        }
+        Ok(a / b)
+    }
+
+    fn rem(a: UBig, b: UBig) -> ScriptResult<UBig> {
+        if b == ubig!(0) {
+            return Err("Division par zéro".into());
+        }
+        Ok(a % b)
+    }
+
+    // Moteur restreint : type `Key` (entier non signé de taille arbitraire) et limites d'exécution
+    fn sandboxed_engine() -> Engine {
+        let mut engine = Engine::new();
+        engine.set_module_resolver(DummyModuleResolver::new());
+        engine.disable_symbol("eval");
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.set_max_call_levels(32);
+        engine.set_max_expr_depths(64, 32);
+        engine.set_max_string_size(4096);
+        engine.set_max_array_size(MAX_SCRIPT_CANDIDATES);
+        engine.set_max_map_size(1024);
+        engine.on_print(|text| println!("[script] {}", text));
+
+        engine.register_type_with_name::<UBig>("Key");
+        engine.register_type_with_name::<KeyRange>("KeyRange");
+        engine.register_fn("key", to_key);
+        engine.register_fn("key", |value: &str| parse_key(value));
+        engine.register_fn("range", |lo: UBig, hi: UBig| KeyRange { lo, hi });
+
+        engine.register_fn("+", add);
+        engine.register_fn("+", |a: UBig, b: INT| add(a, to_key(b)?));
+        engine.register_fn("-", sub);
+        engine.register_fn("-", |a: UBig, b: INT| sub(a, to_key(b)?));
+        engine.register_fn("*", mul);
+        engine.register_fn("*", |a: UBig, b: INT| mul(a, to_key(b)?));
+        engine.register_fn("/", div);
+        engine.register_fn("/", |a: UBig, b: INT| div(a, to_key(b)?));
+        engine.register_fn("%", rem);
+        engine.register_fn("%", |a: UBig, b: INT| rem(a, to_key(b)?));
+        engine.register_fn("==", |a: UBig, b: UBig| a == b);
+        engine.register_fn("!=", |a: UBig, b: UBig| a != b);
+        engine.register_fn("<", |a: UBig, b: UBig| a < b);
+        engine.register_fn("<=", |a: UBig, b: UBig| a <= b);
+        engine.register_fn(">", |a: UBig, b: UBig| a > b);
+        engine.register_fn(">=", |a: UBig, b: UBig| a >= b);
+
+        engine.register_fn("to_string", |key: &mut UBig| key.to_string());
+        engine.register_fn("to_hex", |key: &mut UBig| format!("0x{:x}", key));
+        engine.register_fn("bits", |key: &mut UBig| key.bit_len() as INT);
+        // Bits de poids faible (64 au plus) sous forme d'entier Rhai
+        engine.register_fn("low_bits", |key: &mut UBig, bits: INT| {
+            let bits = bits.clamp(0, 63) as usize;
+            let mask = (ubig!(1) << bits) - ubig!(1);
+            i64::try_from(&*key & &mask).unwrap_or(0)
+        });
+        engine
+    }
+
+    pub struct ScriptGenerator {
+        path: String,
+        engine: Engine,
+        ast: AST,
+        warned: bool,
+    }
+
+    impl ScriptGenerator {
+        pub fn load(path: &str) -> Result<Self, String> {
+            let engine = sandboxed_engine();
+            let ast = engine
+                .compile_file(path.into())
+                .map_err(|e| format!("Script '{}' invalide: {}", path, e))?;
+            if !ast.iter_functions().any(|f| f.name == "candidates" && f.params.len() == 3) {
+                return Err(format!("Script '{}' sans fonction `candidates(base, start, end)`", path));
+            }
+            Ok(Self { path: path.to_string(), engine, ast, warned: false })
+        }
+
+        fn call(&self, base: &UBig, (start, end): (&UBig, &UBig), out: &mut Vec<UBig>) -> Result<(), String> {
+            let items = self.engine
+                .call_fn::<Array>(&mut Scope::new(), &self.ast, "candidates", (base.clone(), start.clone(), end.clone()))
+                .map_err(|e| e.to_string())?;
+
+            let mut remaining = MAX_SCRIPT_CANDIDATES;
+            for item in items {
+                let (lo, hi) = match item_bounds(item)? {
+                    (lo, hi) if lo > hi || hi < *start || lo > *end => continue,
+                    (lo, hi) => (lo.max(start.clone()), hi.min(end.clone())),
+                };
+                let mut key = lo;
+                while key <= hi && remaining > 0 {
+                    out.push(key.clone());
+                    key += ubig!(1);
+                    remaining -= 1;
+                }
+                if remaining == 0 {
+                    break;
+                }
+            }
+            Ok(())
+        }
+
+        // Ajoute à `out` les candidats du script compris dans [start, end] (au plus MAX_SCRIPT_CANDIDATES).
+        // Une erreur d'exécution n'interrompt pas la recherche : seule la première est signalée.
+        pub fn generate(&mut self, base: &UBig, range: (&UBig, &UBig), out: &mut Vec<UBig>) {
+            if let Err(e) = self.call(base, range, out) {
+                if !self.warned {
+                    eprintln!("⚠️  Script '{}' : {} (erreurs suivantes ignorées)", self.path, e);
+                    self.warned = true;
+                }
+            }
+        }
+    }
+
+    // Clé isolée (Key, entier, chaîne) ou sous-plage, sous forme de bornes [lo, hi]
+    fn item_bounds(item: Dynamic) -> Result<(UBig, UBig), String> {
+        let type_name = item.type_name();
+        if let Some(range) = item.clone().try_cast::<KeyRange>() {
+            return Ok((range.lo, range.hi));
+        }
+        let key = if let Some(key) = item.clone().try_cast::<UBig>() {
+            key
+        } else if let Ok(value) = item.as_int() {
+            to_key(value).map_err(|e| e.to_string())?
+        } else if let Ok(text) = item.into_immutable_string() {
+            parse_key(&text).map_err(|e| e.to_string())?
+        } else {
+            return Err(format!("Candidat de type inattendu: {}", type_name));
+        };
+        Ok((key.clone(), key))
+    }
+}
+
+// Sans la fonctionnalité `scripting`, tout script est refusé au démarrage
+#[cfg(not(feature = "scripting"))]
+pub struct ScriptGenerator;
+
+#[cfg(not(feature = "scripting"))]
+impl ScriptGenerator {
+    pub fn load(path: &str) -> Result<Self, String> {
+        Err(format!("Script '{}' ignoré : solveur compilé sans la fonctionnalité 'scripting'", path))
+    }
+
+    pub fn generate(&mut self, _base: &ibig::UBig, _range: (&ibig::UBig, &ibig::UBig), _out: &mut Vec<ibig::UBig>) {}
+}
+
+#[cfg(all(test, feature = "scripting"))]
+mod tests {
+    use super::*;
+    use ibig::{ubig, UBig};
+    use std::fs;
+
+    fn script(name: &str, source: &str) -> String {
+        let path = std::env::temp_dir().join(format!("solver_script_test_{}_{}.rhai", name, std::process::id()));
+        fs::write(&path, source).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    fn generate(path: &str, base: u32, start: u32, end: u32) -> Vec<UBig> {
+        let mut generator = ScriptGenerator::load(path).unwrap();
+        let mut out = Vec::new();
+        generator.generate(&UBig::from(base), (&UBig::from(start), &UBig::from(end)), &mut out);
+        out
+    }
+
+    #[test]
+    fn yields_keys_and_sub_ranges_within_range() {
+        let path = script("mixed", r#"
+            fn candidates(base, start, end) {
+                [base + 7, base * 2, 1005, "0x3f0", range(base - 2, base + 1), range(end - 1, end + 50)]
+            }
+        "#);
+        let keys = generate(&path, 1000, 995, 1010);
+        assert_eq!(keys, [1007u32, 1005, 1008, 998, 999, 1000, 1001, 1009, 1010].map(UBig::from));
+    }
+
+    #[test]
+    fn large_sub_ranges_are_capped() {
+        let path = script("capped", "fn candidates(base, start, end) { [range(start, end)] }");
+        let keys = generate(&path, 5, 1, 1_000_000);
+        assert_eq!(keys.len(), MAX_SCRIPT_CANDIDATES);
+        assert_eq!(keys[0], ubig!(1));
+    }
+
+    #[test]
+    fn handles_256_bit_keys() {
+        let path = script("big", r#"
+            fn candidates(base, start, end) {
+                let k = key("0x8000000000000000000000000000000000000000000000000000000000000000");
+                [k + base, k - 1]
+            }
+        "#);
+        let mut generator = ScriptGenerator::load(&path).unwrap();
+        let (start, end) = (ubig!(1) << 255, (ubig!(1) << 256) - ubig!(1));
+        let mut out = Vec::new();
+        generator.generate(&ubig!(3), (&start, &end), &mut out);
+        assert_eq!(out, [&start + ubig!(3)]);
+    }
+
+    #[test]
+    fn example_script_runs() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/candidates.rhai");
+        let keys = generate(path, 0x12345, 0x10000, 0x1ffff);
+        assert!(keys.contains(&ubig!(0x10000)) && keys.contains(&ubig!(0x1fff5)));
+        assert!(keys.iter().all(|key| *key >= ubig!(0x10000) && *key <= ubig!(0x1ffff)));
+    }
+
+    #[test]
+    fn oversized_keys_are_rejected() {
+        // Élévations au carré successives : 2^(2^40) sans la limite de taille, arrêtées dès 256 bits
+        let path = script("squaring", r#"
+            fn candidates(base, start, end) {
+                let x = key(2);
+                for i in 0..40 { x = x * x; }
+                [x]
+            }
+        "#);
+        assert!(generate(&path, 1000, 1, 2000).is_empty());
+        let path = script("sum", "fn candidates(base, start, end) { let x = key(1); for i in 0..300 { x = x + x; } [x] }");
+        assert!(generate(&path, 1000, 1, 2000).is_empty());
+
+        // 2^255 + 2^255 dépasse 256 bits ; 2^255 + 7 reste accepté
+        let half = "key(\"0x8000000000000000000000000000000000000000000000000000000000000000\")";
+        let path = script("overflow", &format!("fn candidates(base, start, end) {{ [{0} + {0}] }}", half));
+        assert!(generate(&path, 1000, 1, 2000).is_empty());
+        let path = script("limit", &format!("fn candidates(base, start, end) {{ [{} + 7] }}", half));
+        let mut generator = ScriptGenerator::load(&path).unwrap();
+        let mut out = Vec::new();
+        generator.generate(&ubig!(0), (&(ubig!(1) << 255), &((ubig!(1) << 256) - ubig!(1))), &mut out);
+        assert_eq!(out, [(ubig!(1) << 255) + ubig!(7)]);
+    }
+
+    #[test]
+    fn rejects_invalid_scripts() {
+        assert!(ScriptGenerator::load(&script("syntax", "fn candidates(base, start, end) { [base +")).is_err());
+        assert!(ScriptGenerator::load(&script("missing", "fn other(base) { [] }")).is_err());
+        assert!(ScriptGenerator::load("/nonexistent/script.rhai").is_err());
+    }
+
+    #[test]
+    fn runtime_errors_and_runaway_scripts_yield_nothing() {
+        // Boucle infinie arrêtée par la limite d'opérations, import et eval interdits, soustraction négative
+        for (name, source) in [
+            ("loop", "fn candidates(base, start, end) { loop {} }"),
+            ("import", "fn candidates(base, start, end) { import \"other\" as o; [] }"),
+            ("eval", "fn candidates(base, start, end) { eval(\"[1]\") }"),
+            ("negative", "fn candidates(base, start, end) { [base - 2000] }"),
+        ] {
+            let path = script(name, source);
+            match ScriptGenerator::load(&path) {
+                Ok(_) => assert!(generate(&path, 1000, 1, 2000).is_empty(), "script {}", name),
+                Err(_) => assert_ne!(name, "loop"),
+            }
+        }
+    }
+}
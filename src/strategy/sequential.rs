@@ -0,0 +1,91 @@
+// Parcours séquentiel : chaque clé de la plage exactement une fois, dans l'ordre
+use ibig::{ubig, UBig};
+use serde_json::json;
+
+use super::{hex, state_field, SearchMode, SearchStrategy, CHUNK_SIZE};
+use crate::u256::U256;
+
+pub struct SequentialStrategy {
+    start: U256,
+    end: U256,
+    current: U256,
+}
+
+impl SequentialStrategy {
+    pub fn new(start: UBig, end: UBig) -> Self {
+        let start = U256::saturating_from(&start);
+        Self { current: start, start, end: U256::saturating_from(&end) }
+    }
+}
+
+impl SearchStrategy for SequentialStrategy {
+    fn mode(&self) -> SearchMode {
+        SearchMode::Sequential
+    }
+
+    fn next_batch(&mut self, batch: &mut Vec<U256>) -> bool {
+        let limit = batch.len() + CHUNK_SIZE;
+        if self.current > self.end {
+            return false;
+        }
+        while batch.len() < limit && self.current <= self.end {
+            batch.push(self.current);
+            self.current += U256::ONE;
+        }
+        true
+    }
+
+    fn position(&self) -> UBig {
+        self.current.into()
+    }
+
+    fn step(&self) -> Option<U256> {
+        Some(U256::ONE)
+    }
+
+    fn state(&self) -> serde_json::Value {
+        json!({ "position": hex(&self.position()) })
+    }
+
+    fn restore(&mut self, state: &serde_json::Value) -> Result<(), String> {
+        let position = state_field(state, "position")?.ok_or("Point de contrôle sans position")?;
+        if position < UBig::from(self.start) || position > UBig::from(self.end) + ubig!(1) {
+            return Err(format!("Position {} hors de la plage", hex(&position)));
+        }
+        self.current = U256::saturating_from(&position);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visits_every_key_once_in_order() {
+        let mut strategy = SequentialStrategy::new(ubig!(0x10), ubig!(0x21f));
+        let mut keys = Vec::new();
+        while strategy.next_batch(&mut keys) {}
+
+        let expected: Vec<U256> = (0x10u64..=0x21f).map(U256::from).collect();
+        assert_eq!(keys, expected);
+        assert_eq!(strategy.position(), ubig!(0x220));
+    }
+
+    #[test]
+    fn resumes_from_checkpoint() {
+        let mut strategy = SequentialStrategy::new(ubig!(1), ubig!(1000));
+        let mut batch = Vec::new();
+        strategy.next_batch(&mut batch);
+
+        let mut resumed = SequentialStrategy::new(ubig!(1), ubig!(1000));
+        resumed.restore(&strategy.state()).unwrap();
+        let (mut a, mut b) = (Vec::new(), Vec::new());
+        strategy.next_batch(&mut a);
+        resumed.next_batch(&mut b);
+        assert_eq!(a, b);
+        assert_eq!(b[0], U256::from(CHUNK_SIZE as u64 + 1));
+
+        assert!(resumed.restore(&json!({ "position": "0x5000" })).is_err());
+    }
+}
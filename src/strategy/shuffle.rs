@@ -0,0 +1,263 @@
+// Parcours aléatoire sans répétition : la plage est découpée en blocs de `switch_interval` clés,
+// visités dans un ordre pseudo-aléatoire donné par une permutation à clé des indices de bloc
+// (réseau de Feistel + "cycle walking"). Chaque bloc est visité exactement une fois ; l'état se
+// résume au compteur de blocs, à la position dans le bloc et à la clé de la permutation.
+use ibig::{ubig, UBig};
+use serde_json::json;
+
+use super::{hex, state_field, FastRng, SearchMode, SearchStrategy, CHUNK_SIZE};
+use crate::u256::U256;
+
+// Nombre de tours du réseau de Feistel
+const FEISTEL_ROUNDS: u64 = 4;
+
+// Permutation pseudo-aléatoire de [0, n) définie par une clé de 64 bits
+#[derive(Debug, Clone)]
+pub struct BlockPermutation {
+    n: UBig,
+    key: u64,
+    half_bits: usize,
+}
+
+impl BlockPermutation {
+    pub fn new(n: UBig, key: u64) -> Self {
+        // Deux moitiés de `half_bits` bits couvrent [0, n) : 2^(2 * half_bits) < 4n
+        let half_bits = n.bit_len().div_ceil(2);
+        Self { n, key, half_bits: half_bits.max(1) }
+    }
+
+    pub fn len(&self) -> &UBig {
+        &self.n
+    }
+
+    // Fonction de tour : `half_bits` bits pseudo-aléatoires dérivés de la clé, du tour et de la moitié droite
+    fn round(&self, round: u64, right: &UBig) -> UBig {
+        let mut h = mix(self.key ^ round.wrapping_mul(0xd1b5_4a32_d192_ed03));
+        for chunk in right.to_le_bytes().chunks(8) {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            h = mix(h ^ u64::from_le_bytes(word));
+        }
+        let mut value = ubig!(0);
+        for i in 0..self.half_bits.div_ceil(64) as u64 {
+            value = (value << 64) | UBig::from(mix(h ^ i));
+        }
+        value % (ubig!(1) << self.half_bits)
+    }
+
+    // Bijection sur [0, 2^(2 * half_bits)) ; les images hors de [0, n) sont re-permutées
+    // jusqu'à retomber dans le domaine, ce qui reste une bijection de [0, n)
+    pub fn apply(&self, index: &UBig) -> UBig {
+        let mask = (ubig!(1) << self.half_bits) - ubig!(1);
+        let mut value = index.clone();
+        loop {
+            let mut left = &value >> self.half_bits;
+            let mut right = &value & &mask;
+            for round in 0..FEISTEL_ROUNDS {
+                let next = left ^ self.round(round, &right);
+                left = right;
+                right = next;
+            }
+            value = (left << self.half_bits) | right;
+            if value < self.n {
+                return value;
+            }
+        }
+    }
+}
+
+// Mélange splitmix64
+fn mix(z: u64) -> u64 {
+    let mut z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+pub struct ShuffleStrategy {
+    start: UBig,
+    end: UBig,
+    block_size: UBig,
+    permutation: BlockPermutation,
+    // Nombre de blocs entièrement parcourus, et clé courante dans le bloc en cours
+    block: UBig,
+    current: U256,
+}
+
+impl ShuffleStrategy {
+    pub fn new(start: UBig, end: UBig, block_size: u64, mut rng: FastRng) -> Self {
+        let block_size = UBig::from(block_size.max(1));
+        let blocks = (&end - &start + &block_size) / &block_size;
+        let permutation = BlockPermutation::new(blocks, rng.next_u64());
+        let mut strategy = Self { current: U256::ZERO, start, end, block_size, permutation, block: ubig!(0) };
+        strategy.current = U256::saturating_from(&strategy.block_start());
+        strategy
+    }
+
+    fn finished(&self) -> bool {
+        self.block >= *self.permutation.len()
+    }
+
+    fn block_start(&self) -> UBig {
+        &self.start + self.permutation.apply(&self.block) * &self.block_size
+    }
+
+    // Dernière clé du bloc en cours (le dernier bloc peut être incomplet)
+    fn block_end(&self) -> UBig {
+        let end = self.block_start() + &self.block_size - ubig!(1);
+        if end > self.end { self.end.clone() } else { end }
+    }
+}
+
+impl SearchStrategy for ShuffleStrategy {
+    fn mode(&self) -> SearchMode {
+        SearchMode::Shuffle
+    }
+
+    fn next_batch(&mut self, batch: &mut Vec<U256>) -> bool {
+        if self.finished() {
+            return false;
+        }
+        let limit = batch.len() + CHUNK_SIZE;
+        let mut block_end = U256::saturating_from(&self.block_end());
+        while batch.len() < limit {
+            batch.push(self.current);
+            if self.current < block_end {
+                self.current += U256::ONE;
+                continue;
+            }
+            self.block += ubig!(1);
+            if self.finished() {
+                break;
+            }
+            self.current = U256::saturating_from(&self.block_start());
+            block_end = U256::saturating_from(&self.block_end());
+        }
+        true
+    }
+
+    // Une fois la permutation épuisée : fin de plage + 1, comme les parcours séquentiels
+    fn position(&self) -> UBig {
+        if self.finished() { &self.end + ubig!(1) } else { self.current.into() }
+    }
+
+    // Taille des blocs et clé de la permutation des blocs
+    fn parameters(&self) -> serde_json::Value {
+        json!({ "block_size": hex(&self.block_size), "key": self.permutation.key })
+    }
+
+    // La clé de permutation est tirée à chaque démarrage : seule la taille des blocs doit correspondre,
+    // la clé enregistrée est reprise par `restore`
+    fn accepts_parameters(&self, saved: &serde_json::Value) -> bool {
+        saved["block_size"] == json!(hex(&self.block_size))
+    }
+
+    fn state(&self) -> serde_json::Value {
+        json!({
+            "position": hex(&self.position()),
+            "block": hex(&self.block),
+            "key": self.permutation.key,
+        })
+    }
+
+    fn restore(&mut self, state: &serde_json::Value) -> Result<(), String> {
+        let block = state_field(state, "block")?.ok_or("Point de contrôle sans compteur de blocs")?;
+        let key = state.get("key").and_then(|v| v.as_u64()).ok_or("Point de contrôle sans clé de permutation")?;
+        if block > *self.permutation.len() {
+            return Err(format!("Bloc {} hors de la plage", hex(&block)));
+        }
+
+        let mut resumed = Self {
+            start: self.start.clone(),
+            end: self.end.clone(),
+            block_size: self.block_size.clone(),
+            permutation: BlockPermutation::new(self.permutation.len().clone(), key),
+            block,
+            current: U256::saturating_from(&self.end),
+        };
+        if !resumed.finished() {
+            let block_start = resumed.block_start();
+            let position = state_field(state, "position")?.unwrap_or_else(|| block_start.clone());
+            if position < block_start || position > resumed.block_end() {
+                return Err(format!("Position {} hors du bloc en cours", hex(&position)));
+            }
+            resumed.current = U256::saturating_from(&position);
+        }
+        *self = resumed;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn permutation_is_a_bijection() {
+        for n in [1u32, 2, 7, 64, 1000, 4097] {
+            let permutation = BlockPermutation::new(UBig::from(n), 0x5eed);
+            let images: HashSet<UBig> = (0..n).map(|i| permutation.apply(&UBig::from(i))).collect();
+            assert_eq!(images.len(), n as usize);
+            assert!(images.iter().all(|image| *image < UBig::from(n)));
+        }
+        // Ordre différent selon la clé, et différent de l'identité
+        let order = |key| (0..64u32).map(|i| BlockPermutation::new(ubig!(64), key).apply(&UBig::from(i))).collect::<Vec<_>>();
+        assert_ne!(order(1), order(2));
+        assert_ne!(order(1), (0..64u32).map(UBig::from).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn permutation_handles_256_bit_domains() {
+        let permutation = BlockPermutation::new((ubig!(1) << 236) + ubig!(5), 9);
+        let images: HashSet<UBig> = (0..100u32).map(|i| permutation.apply(&UBig::from(i))).collect();
+        assert_eq!(images.len(), 100);
+        assert!(images.iter().all(|image| *image < *permutation.len()));
+    }
+
+    #[test]
+    fn visits_every_key_exactly_once() {
+        let mut strategy = ShuffleStrategy::new(ubig!(1000), ubig!(5002), 64, FastRng::new(4));
+        let mut keys = Vec::new();
+        while strategy.next_batch(&mut keys) {}
+
+        assert_eq!(keys.len(), 4003);
+        let distinct: HashSet<&U256> = keys.iter().collect();
+        assert_eq!(distinct.len(), 4003);
+        assert!(keys.iter().all(|key| *key >= U256::from(1000) && *key <= U256::from(5002)));
+        // Les blocs ne sont pas parcourus dans l'ordre
+        assert!(keys.windows(2).any(|pair| pair[1] < pair[0]));
+    }
+
+    #[test]
+    fn resumes_from_checkpoint() {
+        let mut strategy = ShuffleStrategy::new(ubig!(1), ubig!(100_000), 100, FastRng::new(3));
+        let mut batch = Vec::new();
+        strategy.next_batch(&mut batch);
+        strategy.next_batch(&mut batch);
+
+        let mut resumed = ShuffleStrategy::new(ubig!(1), ubig!(100_000), 100, FastRng::new(99));
+        resumed.restore(&strategy.state()).unwrap();
+        let (mut a, mut b) = (Vec::new(), Vec::new());
+        for _ in 0..4 {
+            strategy.next_batch(&mut a);
+            resumed.next_batch(&mut b);
+        }
+        assert_eq!(a, b);
+
+        // Un ancien point de contrôle (simple position) ne suffit pas à reprendre l'ordre des blocs
+        assert!(resumed.restore(&json!({ "position": "0x10" })).is_err());
+    }
+
+    #[test]
+    fn position_is_past_the_end_once_exhausted() {
+        let mut strategy = ShuffleStrategy::new(ubig!(1000), ubig!(5002), 64, FastRng::new(4));
+        while strategy.next_batch(&mut Vec::new()) {}
+        assert_eq!(strategy.position(), ubig!(5003));
+
+        let mut resumed = ShuffleStrategy::new(ubig!(1000), ubig!(5002), 64, FastRng::new(5));
+        resumed.restore(&strategy.state()).unwrap();
+        assert_eq!(resumed.position(), ubig!(5003));
+        assert!(!resumed.next_batch(&mut Vec::new()));
+    }
+}
@@ -0,0 +1,229 @@
+// Parcours "smart" : parcours aléatoire dont chaque clé de base est complétée par les patterns des
+// familles configurées (`smart_patterns`, voir `PatternFamily`). Seules des clés de la plage sont produites,
+// et une fenêtre des dernières clés produites évite de retester les voisins qui se recouvrent d'une clé
+// de base à la suivante. Un script Rhai (`smart_script`, voir `ScriptGenerator`) peut compléter les familles.
+// Avec `subinterval_ratio`, les clés de base parcourent des sous-intervalles (voir `RandomStrategy`).
+use ibig::UBig;
+use std::collections::{HashSet, VecDeque};
+
+use super::{generate_key_patterns, rng_field, FastRng, PatternFamily, RandomStrategy, ScriptGenerator, SearchMode, SearchStrategy, CHUNK_SIZE};
+use crate::u256::U256;
+
+// Taille de la fenêtre de déduplication (dernières clés produites)
+const RECENT_KEYS: usize = 8192;
+
+// Dernières clés produites, de la plus ancienne à la plus récente
+#[derive(Default)]
+struct RecentKeys {
+    order: VecDeque<U256>,
+    keys: HashSet<U256>,
+}
+
+impl RecentKeys {
+    // false si la clé a déjà été produite récemment
+    fn insert(&mut self, key: U256) -> bool {
+        if !self.keys.insert(key) {
+            return false;
+        }
+        self.order.push_back(key);
+        if self.order.len() > RECENT_KEYS {
+            if let Some(oldest) = self.order.pop_front() {
+                self.keys.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+pub struct SmartStrategy {
+    start: U256,
+    end: U256,
+    walk: RandomStrategy,
+    families: Vec<PatternFamily>,
+    script: Option<ScriptGenerator>,
+    rng: FastRng,
+    recent: RecentKeys,
+}
+
+impl SmartStrategy {
+    pub fn new(start: UBig, end: UBig, switch_interval: u64, families: Vec<PatternFamily>, mut rng: FastRng) -> Self {
+        let pattern_rng = FastRng::new(rng.next_u64());
+        Self {
+            start: U256::saturating_from(&start),
+            end: U256::saturating_from(&end),
+            walk: RandomStrategy::new(start, end, switch_interval, rng),
+            families,
+            script: None,
+            rng: pattern_rng,
+            recent: RecentKeys::default(),
+        }
+    }
+
+    pub fn with_script(mut self, script: ScriptGenerator) -> Self {
+        self.script = Some(script);
+        self
+    }
+
+    pub fn with_subinterval_ratio(mut self, ratio: f64) -> Self {
+        self.walk = self.walk.with_subinterval_ratio(ratio);
+        self
+    }
+}
+
+impl SearchStrategy for SmartStrategy {
+    fn mode(&self) -> SearchMode {
+        SearchMode::Smart
+    }
+
+    fn next_batch(&mut self, batch: &mut Vec<U256>) -> bool {
+        // Sans famille de patterns ni script : parcours aléatoire simple
+        if self.families.is_empty() && self.script.is_none() {
+            return self.walk.next_batch(batch);
+        }
+        if self.walk.finished() {
+            return false;
+        }
+        let limit = batch.len() + CHUNK_SIZE;
+
+        // Au plus CHUNK_SIZE clés de base par lot : sur une petite plage déjà couverte par la
+        // fenêtre de déduplication, le lot peut rester vide
+        for _ in 0..CHUNK_SIZE {
+            if batch.len() >= limit || self.walk.finished() {
+                break;
+            }
+            let before = batch.len();
+            let base = *self.walk.current();
+            if self.recent.insert(base) {
+                batch.push(base);
+            }
+            let candidates = generate_key_patterns(&base, &self.families, (&self.start, &self.end), &mut self.rng);
+            for key in candidates {
+                if self.recent.insert(key) {
+                    batch.push(key);
+                }
+            }
+            // Le script Rhai travaille sur des entiers arbitraires : conversion à sa frontière seulement
+            if let Some(script) = &mut self.script {
+                let mut scripted = Vec::new();
+                script.generate(&UBig::from(base), (&UBig::from(self.start), &UBig::from(self.end)), &mut scripted);
+                for key in scripted.iter().map(U256::saturating_from) {
+                    if self.recent.insert(key) {
+                        batch.push(key);
+                    }
+                }
+            }
+            self.walk.advance((batch.len() - before) as u64);
+        }
+        true
+    }
+
+    fn position(&self) -> UBig {
+        self.walk.position()
+    }
+
+    // État du parcours, complété par le générateur des patterns (`pattern_rng`) : une reprise produit
+    // les mêmes candidats qu'une exécution ininterrompue (hors fenêtre de déduplication, non enregistrée)
+    fn state(&self) -> serde_json::Value {
+        let mut state = self.walk.state();
+        state["pattern_rng"] = serde_json::json!(self.rng.state());
+        state
+    }
+
+    fn restore(&mut self, state: &serde_json::Value) -> Result<(), String> {
+        let pattern_rng = rng_field(state, "pattern_rng")?;
+        self.walk.restore(state)?;
+        if let Some(rng) = pattern_rng {
+            self.rng = rng;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ibig::ubig;
+
+    #[test]
+    fn batches_start_with_base_key_and_its_neighbours() {
+        let mut strategy = SmartStrategy::new(ubig!(1000), ubig!(1_000_000), 1000, vec![PatternFamily::Neighbours], FastRng::new(5));
+        let base = *strategy.walk.current();
+        let mut batch = Vec::new();
+        strategy.next_batch(&mut batch);
+
+        assert_eq!(batch[0], base);
+        assert!(batch.contains(&(base + U256::from(13))));
+        assert!(batch.contains(&(base - U256::from(13))));
+    }
+
+    #[test]
+    fn candidates_are_in_range_and_not_repeated() {
+        let mut strategy = SmartStrategy::new(ubig!(0x40000), ubig!(0x7ffff), 1000, PatternFamily::ALL.to_vec(), FastRng::new(8));
+        let mut batch = Vec::new();
+        for _ in 0..20 {
+            strategy.next_batch(&mut batch);
+        }
+
+        assert!(batch.iter().all(|key| *key >= U256::from(0x40000) && *key <= U256::from(0x7ffff)));
+        // Moins de RECENT_KEYS clés produites : aucune n'est répétée
+        assert!(batch.len() < RECENT_KEYS);
+        let distinct: HashSet<&U256> = batch.iter().collect();
+        assert_eq!(distinct.len(), batch.len());
+    }
+
+    #[test]
+    fn small_covered_range_yields_empty_batches() {
+        let mut strategy = SmartStrategy::new(ubig!(10), ubig!(20), 1000, vec![PatternFamily::Neighbours], FastRng::new(5));
+        let mut batch = Vec::new();
+        strategy.next_batch(&mut batch);
+        assert_eq!(batch.len(), 11);
+
+        batch.clear();
+        assert!(strategy.next_batch(&mut batch));
+        assert!(batch.is_empty());
+    }
+
+    #[test]
+    fn base_keys_follow_subintervals_until_range_is_covered() {
+        let mut strategy = SmartStrategy::new(ubig!(0x40000), ubig!(0x40fff), 1000, vec![PatternFamily::Neighbours], FastRng::new(6))
+            .with_subinterval_ratio(0.05);
+        let mut keys = Vec::new();
+        while strategy.next_batch(&mut keys) {}
+
+        let distinct: HashSet<&U256> = keys.iter().collect();
+        assert_eq!(distinct.len(), 0x1000);
+        assert!(keys.iter().all(|key| *key >= U256::from(0x40000) && *key <= U256::from(0x40fff)));
+    }
+
+    #[test]
+    fn without_patterns_behaves_like_random_walk() {
+        let mut strategy = SmartStrategy::new(ubig!(10), ubig!(20), 1000, Vec::new(), FastRng::new(5));
+        let mut batch = Vec::new();
+        strategy.next_batch(&mut batch);
+        assert_eq!(batch.len(), CHUNK_SIZE);
+        assert!(batch.iter().all(|key| *key >= U256::from(10) && *key <= U256::from(20)));
+    }
+
+    #[test]
+    fn resumes_the_same_candidates_from_checkpoint() {
+        let families = vec![PatternFamily::Neighbours, PatternFamily::Shuffled, PatternFamily::Reversed];
+        let new = || SmartStrategy::new(ubig!(1) << 40, ubig!(1) << 41, 50, families.clone(), FastRng::new(11));
+        let next = |strategy: &mut SmartStrategy| {
+            let mut batch = Vec::new();
+            strategy.next_batch(&mut batch);
+            batch
+        };
+
+        let mut uninterrupted = new();
+        let mut interrupted = new();
+        for _ in 0..3 {
+            assert_eq!(next(&mut interrupted), next(&mut uninterrupted));
+        }
+        let state = serde_json::from_str(&interrupted.state().to_string()).unwrap();
+        let mut resumed = new();
+        resumed.restore(&state).unwrap();
+        for _ in 0..3 {
+            assert_eq!(next(&mut resumed), next(&mut uninterrupted));
+        }
+    }
+}
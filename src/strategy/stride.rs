@@ -0,0 +1,118 @@
+// Parcours par pas constant : clés start + i * stride, dans l'ordre croissant de i
+use ibig::{ubig, UBig};
+use serde_json::json;
+
+use super::{hex, state_field, SearchMode, SearchStrategy, CHUNK_SIZE};
+use crate::u256::U256;
+
+pub struct StrideStrategy {
+    start: UBig,
+    end: UBig,
+    stride: UBig,
+    index: UBig,
+    // Fin et pas sur 256 bits pour la boucle par clé (un pas de plus de 256 bits sature)
+    last: U256,
+    step: U256,
+}
+
+impl StrideStrategy {
+    pub fn new(start: UBig, end: UBig, stride: UBig) -> Self {
+        let stride = if stride == ubig!(0) { ubig!(1) } else { stride };
+        let (last, step) = (U256::saturating_from(&end), U256::saturating_from(&stride));
+        Self { start, end, stride, index: ubig!(0), last, step }
+    }
+
+    fn key_at(&self, index: &UBig) -> UBig {
+        &self.start + index * &self.stride
+    }
+}
+
+impl SearchStrategy for StrideStrategy {
+    fn mode(&self) -> SearchMode {
+        SearchMode::Stride
+    }
+
+    fn next_batch(&mut self, batch: &mut Vec<U256>) -> bool {
+        let key = self.key_at(&self.index);
+        if key > self.end {
+            return false;
+        }
+        let limit = batch.len() + CHUNK_SIZE;
+        let mut key = Some(U256::saturating_from(&key));
+        let mut count = 0u64;
+        while let Some(current) = key.filter(|current| batch.len() < limit && *current <= self.last) {
+            batch.push(current);
+            key = current.checked_add(&self.step);
+            count += 1;
+        }
+        self.index += UBig::from(count);
+        true
+    }
+
+    fn position(&self) -> UBig {
+        self.key_at(&self.index)
+    }
+
+    fn step(&self) -> Option<U256> {
+        U256::try_from(&self.stride).ok()
+    }
+
+    // Pas et classe de résidus (décalage modulo le pas) de la progression
+    fn parameters(&self) -> serde_json::Value {
+        json!({ "stride": hex(&self.stride), "offset": hex(&(&self.start % &self.stride)) })
+    }
+
+    fn state(&self) -> serde_json::Value {
+        json!({ "position": hex(&self.position()), "index": hex(&self.index) })
+    }
+
+    // L'index fait foi ; à défaut (ancien point de contrôle) on reprend au premier terme >= position
+    fn restore(&mut self, state: &serde_json::Value) -> Result<(), String> {
+        let index = match state_field(state, "index")? {
+            Some(index) => index,
+            None => {
+                let position = state_field(state, "position")?.ok_or("Point de contrôle sans position")?;
+                if position <= self.start {
+                    ubig!(0)
+                } else {
+                    (&position - &self.start + &self.stride - ubig!(1)) / &self.stride
+                }
+            }
+        };
+        if self.key_at(&index) > &self.end + &self.stride {
+            return Err(format!("Index {} hors de la plage", hex(&index)));
+        }
+        self.index = index;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn yields_arithmetic_progression_within_range() {
+        let mut strategy = StrideStrategy::new(ubig!(100), ubig!(10_000), ubig!(7));
+        let mut keys = Vec::new();
+        while strategy.next_batch(&mut keys) {}
+
+        let expected: Vec<U256> = (0..).map(|i: u64| U256::from(100 + 7 * i)).take_while(|k| *k <= U256::from(10_000)).collect();
+        assert_eq!(keys, expected);
+    }
+
+    #[test]
+    fn resumes_from_index_or_legacy_position() {
+        let mut strategy = StrideStrategy::new(ubig!(0), ubig!(1_000_000), ubig!(10));
+        let mut batch = Vec::new();
+        strategy.next_batch(&mut batch);
+        assert_eq!(strategy.state()["index"], hex(&UBig::from(CHUNK_SIZE)));
+
+        let mut resumed = StrideStrategy::new(ubig!(0), ubig!(1_000_000), ubig!(10));
+        resumed.restore(&strategy.state()).unwrap();
+        assert_eq!(resumed.position(), strategy.position());
+
+        resumed.restore(&json!({ "position": "0x0f" })).unwrap();
+        assert_eq!(resumed.position(), ubig!(20));
+    }
+}
@@ -0,0 +1,147 @@
+// Ensemble des adresses recherchées (fichier puzzle.txt)
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::hashes::Hash;
+use bitcoin::{Address, Network, PubkeyHash, PublicKey};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::batch::KeyHashes;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+#[derive(Debug, Clone, Default)]
+pub struct TargetSet {
+    pub addresses: HashSet<String>,
+    // Empreintes HASH160 des adresses P2PKH, comparées aux clés dérivées par lots
+    hashes: HashSet<[u8; 20]>,
+    // Cibles données par leur clé publique (modes bsgs et kangaroo) ; leur adresse P2PKH est aussi dans `addresses`
+    public_keys: Vec<PublicKeyTarget>,
+}
+
+// Clé publique cible : `text` est sa forme hexadécimale normalisée, reportée dans les résultats
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKeyTarget {
+    pub text: String,
+    pub key: PublicKey,
+}
+
+impl TargetSet {
+    // Une adresse ou une clé publique (hexadécimale, compressée ou non) par ligne ;
+    // lignes vides et commentaires (#) ignorés
+    pub fn load(path: &str) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| format!("Impossible d'ouvrir le fichier puzzle : {} ({})", path, e))?;
+        let reader = BufReader::new(file);
+
+        let mut lines = Vec::new();
+
+        for line in reader.lines().map_while(Result::ok) {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') { continue; }
+
+            lines.push(line.to_string());
+        }
+
+        Ok(Self::from_targets(lines))
+    }
+
+    // Adresses et clés publiques mêlées : une clé publique est reconnue à sa forme hexadécimale
+    // (66 caractères 02/03..., ou 130 caractères 04...) et recherchée aussi par son adresse P2PKH
+    pub fn from_targets<I: IntoIterator<Item = S>, S: Into<String>>(targets: I) -> Self {
+        let mut addresses = Vec::new();
+        let mut public_keys: Vec<PublicKeyTarget> = Vec::new();
+        for target in targets.into_iter().map(Into::into) {
+            match parse_public_key(&target) {
+                Some(key) => {
+                    addresses.push(Address::p2pkh(key, Network::Bitcoin).to_string());
+                    if !public_keys.iter().any(|known| known.key == key) {
+                        public_keys.push(PublicKeyTarget { text: key.to_string(), key });
+                    }
+                },
+                None => addresses.push(target),
+            }
+        }
+        Self { public_keys, ..Self::from_addresses(addresses) }
+    }
+
+    pub fn from_addresses<I: IntoIterator<Item = S>, S: Into<String>>(addresses: I) -> Self {
+        let addresses: HashSet<String> = addresses.into_iter().map(Into::into).collect();
+        let hashes = addresses.iter().filter_map(|address| p2pkh_hash(address)).collect();
+        Self { addresses, hashes, public_keys: Vec::new() }
+    }
+
+    pub fn contains(&self, address: &str) -> bool {
+        self.addresses.contains(address)
+    }
+
+    // Clés du lot dont une empreinte correspond à une cible : (indice dans le lot, adresse), dans l'ordre du lot
+    pub fn probe(&self, hashes: &[Option<KeyHashes>]) -> Vec<(usize, String)> {
+        let mut hits = Vec::new();
+        for (index, key_hashes) in hashes.iter().enumerate() {
+            for hash in key_hashes.iter().flatten() {
+                if self.hashes.contains(hash) {
+                    let address = Address::p2pkh(PubkeyHash::from_byte_array(*hash), Network::Bitcoin);
+                    hits.push((index, address.to_string()));
+                }
+            }
+        }
+        hits
+    }
+
+    pub fn public_keys(&self) -> &[PublicKeyTarget] {
+        &self.public_keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+}
+
+// Empreinte d'une adresse P2PKH du réseau principal (None pour tout autre format)
+fn p2pkh_hash(address: &str) -> Option<[u8; 20]> {
+    let address = Address::<NetworkUnchecked>::from_str(address).ok()?.require_network(Network::Bitcoin).ok()?;
+    address.pubkey_hash().map(|hash| hash.to_byte_array())
+}
+
+// Clé publique au format hexadécimal SEC1 (None pour tout autre texte, adresses comprises)
+fn parse_public_key(text: &str) -> Option<PublicKey> {
+    let hex_form = matches!((text.len(), text.get(..2)?), (66, "02" | "03") | (130, "04"));
+    hex_form.then(|| PublicKey::from_str(text).ok()).flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn probes_p2pkh_hashes_in_batch_order() {
+        let targets = TargetSet::from_addresses(["1HduPEXZRdG26SUT5Yk83mLkPyjnZuJ7Bm", "pas une adresse"]);
+        let hash = p2pkh_hash("1HduPEXZRdG26SUT5Yk83mLkPyjnZuJ7Bm").unwrap();
+        let hits = targets.probe(&[None, Some([[0; 20], [1; 20]]), Some([[0; 20], hash]), Some([hash, hash])]);
+        let address = "1HduPEXZRdG26SUT5Yk83mLkPyjnZuJ7Bm".to_string();
+        assert_eq!(hits, [(2, address.clone()), (3, address.clone()), (3, address)]);
+        assert!(targets.contains("pas une adresse"));
+    }
+
+    #[test]
+    fn public_keys_are_searched_directly_and_by_address() {
+        // Puzzle 1 (clé 1) : clé publique compressée de G, et forme non compressée en majuscules
+        let g = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let g_uncompressed = "0479BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798\
+            483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8";
+        let targets = TargetSet::from_targets([g, g, g_uncompressed, "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"]);
+
+        let keys: Vec<&str> = targets.public_keys().iter().map(|target| target.text.as_str()).collect();
+        assert_eq!(keys, [g, &g_uncompressed.to_lowercase()]);
+        assert!(targets.public_keys()[0].key.compressed && !targets.public_keys()[1].key.compressed);
+        // Adresses compressée (puzzle 1) et non compressée de la clé 1
+        assert_eq!(targets.len(), 2);
+        assert!(targets.contains("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"));
+        assert!(targets.contains("1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm"));
+        assert!(!targets.contains(g));
+        assert_eq!(TargetSet::from_targets(["02abc", &g[..64]]).public_keys().len(), 0);
+    }
+}
@@ -0,0 +1,320 @@
+// Entier non signé de 256 bits sur la pile (4 mots de 64 bits, poids croissant) : type des clés dans
+// les boucles par clé (stratégies, dérivation, workers). `UBig` reste réservé à la lecture de la
+// configuration, aux points de contrôle, aux calculs par saut ou par bloc et à l'affichage.
+use ibig::UBig;
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use std::ops::{Add, AddAssign, BitAnd, BitOr, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct U256([u64; 4]);
+
+impl U256 {
+    pub const ZERO: Self = Self([0; 4]);
+    pub const ONE: Self = Self([1, 0, 0, 0]);
+    pub const MAX: Self = Self([u64::MAX; 4]);
+    // Ordre n du groupe secp256k1 : les clés privées valides sont 1..n-1
+    pub const CURVE_ORDER: Self = Self([0xbfd2_5e8c_d036_4141, 0xbaae_dce6_af48_a03b, 0xffff_ffff_ffff_fffe, u64::MAX]);
+
+    pub const fn from_u64(value: u64) -> Self {
+        Self([value, 0, 0, 0])
+    }
+
+    // Valeur de `value`, ou U256::MAX si elle dépasse 256 bits
+    pub fn saturating_from(value: &UBig) -> Self {
+        Self::try_from(value).unwrap_or(Self::MAX)
+    }
+
+    pub fn from_be_bytes(bytes: &[u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let offset = 24 - 8 * i;
+            *limb = u64::from_be_bytes(bytes[offset..offset + 8].try_into().unwrap());
+        }
+        Self(limbs)
+    }
+
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, limb) in self.0.iter().enumerate() {
+            let offset = 24 - 8 * i;
+            bytes[offset..offset + 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    // 64 bits de poids faible
+    pub fn low_u64(&self) -> u64 {
+        self.0[0]
+    }
+
+    // Valeur sur 64 bits si elle y tient
+    pub fn to_u64(&self) -> Option<u64> {
+        (self.0[1..] == [0; 3]).then_some(self.0[0])
+    }
+
+    pub fn bit_len(&self) -> usize {
+        for i in (0..4).rev() {
+            if self.0[i] != 0 {
+                return 64 * i + 64 - self.0[i].leading_zeros() as usize;
+            }
+        }
+        0
+    }
+
+    pub fn bit(&self, index: usize) -> bool {
+        index < 256 && (self.0[index / 64] >> (index % 64)) & 1 == 1
+    }
+
+    pub fn set_bit(&mut self, index: usize) {
+        self.0[index / 64] |= 1 << (index % 64);
+    }
+
+    pub fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+        let mut out = [0u64; 4];
+        let mut carry = false;
+        for (i, limb) in out.iter_mut().enumerate() {
+            let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = c1 || c2;
+        }
+        (Self(out), carry)
+    }
+
+    pub fn overflowing_sub(&self, other: &Self) -> (Self, bool) {
+        let mut out = [0u64; 4];
+        let mut borrow = false;
+        for (i, limb) in out.iter_mut().enumerate() {
+            let (difference, b1) = self.0[i].overflowing_sub(other.0[i]);
+            let (difference, b2) = difference.overflowing_sub(borrow as u64);
+            *limb = difference;
+            borrow = b1 || b2;
+        }
+        (Self(out), borrow)
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        match self.overflowing_add(other) {
+            (sum, false) => Some(sum),
+            _ => None,
+        }
+    }
+
+    pub fn wrapping_add(&self, other: &Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        match self.overflowing_sub(other) {
+            (difference, false) => Some(difference),
+            _ => None,
+        }
+    }
+
+    pub fn checked_mul_u64(&self, factor: u64) -> Option<Self> {
+        let mut out = [0u64; 4];
+        let mut carry = 0u128;
+        for (i, limb) in out.iter_mut().enumerate() {
+            let product = self.0[i] as u128 * factor as u128 + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        (carry == 0).then_some(Self(out))
+    }
+
+    // Quotient et reste de la division par `divisor` (non nul)
+    pub fn div_rem_u64(&self, divisor: u64) -> (Self, u64) {
+        let mut out = [0u64; 4];
+        let mut remainder = 0u128;
+        for i in (0..4).rev() {
+            let current = (remainder << 64) | self.0[i] as u128;
+            out[i] = (current / divisor as u128) as u64;
+            remainder = current % divisor as u128;
+        }
+        (Self(out), remainder as u64)
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Comme pour les entiers primitifs, un débordement est une erreur de programmation
+impl Add for U256 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(&other).expect("débordement de U256")
+    }
+}
+
+impl AddAssign for U256 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for U256 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(&other).expect("soustraction négative de U256")
+    }
+}
+
+impl BitAnd for U256 {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self([0, 1, 2, 3].map(|i| self.0[i] & other.0[i]))
+    }
+}
+
+impl BitOr for U256 {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self([0, 1, 2, 3].map(|i| self.0[i] | other.0[i]))
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> Self {
+        Self::from_u64(value)
+    }
+}
+
+impl TryFrom<&UBig> for U256 {
+    type Error = String;
+
+    fn try_from(value: &UBig) -> Result<Self, Self::Error> {
+        let bytes = value.to_le_bytes();
+        if bytes.len() > 32 {
+            return Err(format!("Valeur de plus de 256 bits: 0x{:x}", value));
+        }
+        let mut limbs = [0u64; 4];
+        for (i, chunk) in bytes.chunks(8).enumerate() {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            limbs[i] = u64::from_le_bytes(word);
+        }
+        Ok(Self(limbs))
+    }
+}
+
+impl From<U256> for UBig {
+    fn from(value: U256) -> Self {
+        UBig::from_be_bytes(&value.to_be_bytes())
+    }
+}
+
+// Écriture décimale par tranches de 19 chiffres (10^19 tient sur 64 bits)
+impl fmt::Display for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut chunks = Vec::new();
+        let mut value = *self;
+        loop {
+            let (quotient, chunk) = value.div_rem_u64(CHUNK);
+            chunks.push(chunk);
+            if quotient == U256::ZERO {
+                break;
+            }
+            value = quotient;
+        }
+        let mut text = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            text.push_str(&format!("{:019}", chunk));
+        }
+        f.pad(&text)
+    }
+}
+
+// Lecture décimale ; une valeur de plus de 256 bits est une erreur
+impl FromStr for U256 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("Nombre décimal vide".to_string());
+        }
+        s.bytes().try_fold(U256::ZERO, |value, byte| {
+            let digit = (byte as char).to_digit(10).ok_or_else(|| format!("Nombre décimal invalide: {}", s))?;
+            value.checked_mul_u64(10)
+                .and_then(|value| value.checked_add(&U256::from(digit as u64)))
+                .ok_or_else(|| format!("Valeur de plus de 256 bits: {}", s))
+        })
+    }
+}
+
+impl fmt::LowerHex for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&UBig::from(*self), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ibig::ubig;
+
+    #[test]
+    fn round_trips_through_ubig_and_bytes() {
+        for value in [ubig!(0), ubig!(1), ubig!(0x1764f), (ubig!(1) << 200) + ubig!(0xabc), (ubig!(1) << 256) - ubig!(1)] {
+            let key = U256::try_from(&value).unwrap();
+            assert_eq!(UBig::from(key), value);
+            assert_eq!(U256::from_be_bytes(&key.to_be_bytes()), key);
+            assert_eq!(key.bit_len(), value.bit_len());
+            assert_eq!(format!("{:x}", key), format!("{:x}", value));
+        }
+        assert!(U256::try_from(&(ubig!(1) << 256)).is_err());
+        let order = UBig::from_str_radix("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141", 16).unwrap();
+        assert_eq!(UBig::from(U256::CURVE_ORDER), order);
+        assert_eq!(U256::saturating_from(&(ubig!(1) << 300)), U256::MAX);
+    }
+
+    #[test]
+    fn arithmetic_carries_across_words() {
+        let low_max = U256::from(u64::MAX);
+        let carried = low_max + U256::ONE;
+        assert_eq!(UBig::from(carried), ubig!(1) << 64);
+        assert_eq!(carried - U256::ONE, low_max);
+        assert!(carried > low_max && U256::MAX > carried);
+        assert_eq!(U256::MAX.checked_add(&U256::ONE), None);
+        assert_eq!(U256::MAX.wrapping_add(&U256::ONE), U256::ZERO);
+        assert_eq!(U256::ZERO.checked_sub(&U256::ONE), None);
+        assert_eq!(carried.to_u64(), None);
+        assert_eq!(low_max.to_u64(), Some(u64::MAX));
+
+        assert_eq!(low_max.checked_mul_u64(13).map(UBig::from), Some(ubig!(0xffffffffffffffff) * 13u8));
+        assert_eq!(U256::MAX.checked_mul_u64(2), None);
+        assert_eq!(carried.div_rem_u64(10), (U256::from(1844674407370955161), 6));
+
+        let mut bits = U256::ZERO;
+        bits.set_bit(200);
+        assert!(bits.bit(200) && !bits.bit(199));
+        assert_eq!((bits | U256::ONE) & bits, bits);
+    }
+
+    #[test]
+    fn decimal_round_trips() {
+        for value in [ubig!(0), ubig!(7), ubig!(10_000_000_000_000_000_000), (ubig!(1) << 200) + ubig!(12345), (ubig!(1) << 256) - ubig!(1)] {
+            let key = U256::try_from(&value).unwrap();
+            assert_eq!(key.to_string(), value.to_string());
+            assert_eq!(key.to_string().parse::<U256>(), Ok(key));
+        }
+        assert!("".parse::<U256>().is_err());
+        assert!("12a".parse::<U256>().is_err());
+        assert!(((ubig!(1) << 256).to_string()).parse::<U256>().is_err());
+    }
+}
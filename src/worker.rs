@@ -0,0 +1,277 @@
+// Threads de recherche CPU et GPU
+use bitcoin::secp256k1::Secp256k1;
+use ibig::{ubig, UBig};
+use rand::Rng;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::checkpoint::{load_checkpoint, save_checkpoint};
+use crate::config::Config;
+use crate::dlp::{bsgs, kangaroo, DlpOutcome, DlpSearch};
+use crate::gpu::GPUWorker;
+use crate::batch::KeyDeriver;
+use crate::keys::secret_key_from_u256;
+use crate::results::{Backend, FoundKey, ResultHandler};
+use crate::stats::{Control, Statistics};
+use crate::strategy::{build_strategy, worker_seed, FastRng, SearchMode, CHUNK_SIZE, GPU_STREAM};
+use crate::targets::TargetSet;
+use crate::u256::U256;
+
+// État partagé transmis à chaque worker
+#[derive(Clone)]
+pub struct WorkerContext {
+    pub config: Arc<Config>,
+    pub targets: Arc<TargetSet>,
+    pub stats: Arc<Statistics>,
+    pub found: Arc<AtomicBool>,
+    pub results: Arc<ResultHandler>,
+    pub control: Arc<Control>,
+}
+
+// Lance un worker dans un thread nommé (le nom apparaît dans les alertes de panique)
+pub fn spawn_worker<F: FnOnce() + Send + 'static>(name: String, body: F) -> thread::JoinHandle<()> {
+    thread::Builder::new()
+        .name(name)
+        .spawn(body)
+        .expect("Impossible de démarrer le thread worker")
+}
+
+// Worker GPU
+pub fn gpu_worker_thread(device_id: usize, ctx: WorkerContext, core_start: UBig, core_end: UBig) {
+    let WorkerContext { config, targets, stats, found, results, control } = ctx;
+    let gpu_worker = GPUWorker::new(device_id, config.gpu_batch_size);
+    let secp = Secp256k1::new();
+    let seed = config.seed.unwrap_or_else(|| rand::thread_rng().gen());
+    let mut rng = FastRng::new(worker_seed(seed, GPU_STREAM + device_id as u64));
+    let worker = stats.register_worker(Backend::Gpu, device_id, &core_start, &core_end);
+    let _finished = worker.finish_guard(&control, &found);
+    
+    let mask_indices = match (config.mode, &config.mask) {
+        (SearchMode::Mask, Some(mask)) => {
+            let (first, last) = mask.index_range(&core_start, &core_end);
+            let count = if last > first { last - &first } else { ubig!(0) };
+            Some((mask.clone(), first, count))
+        },
+        _ => None,
+    };
+    
+    // Le solveur garantit des bornes inférieures à l'ordre de la courbe, donc sur 256 bits
+    let (key_start, key_range) = (U256::saturating_from(&core_start), U256::saturating_from(&(&core_end - &core_start + ubig!(1))));
+
+    println!("🚀 [GPU {}] Worker GPU (simulation) démarré", device_id);
+    
+    loop {
+        if (found.load(Ordering::Relaxed) && config.stop_on_find) || control.stop.load(Ordering::Relaxed) {
+            break;
+        }
+        if control.paused.load(Ordering::Relaxed) {
+            thread::sleep(Duration::from_millis(100));
+            continue;
+        }
+        
+        // Générer un lot de clés pour le GPU (en mode mask, parmi les seules clés compatibles)
+        let mut keys_batch = Vec::new();
+        
+        for _ in 0..config.gpu_batch_size {
+            keys_batch.push(match &mask_indices {
+                Some((mask, first, count)) => U256::saturating_from(&mask.key_at(&(first + rng.gen_below(count)))),
+                None => key_start + rng.gen_below_key(&key_range),
+            });
+        }
+        
+        // Traitement par le GPU
+        worker.set_position(&keys_batch[0].into());
+        let batch_results = gpu_worker.process_key_batch(&keys_batch);
+        
+        for (key_val, secret_key, addresses) in batch_results {
+            for address_str in addresses {
+                if targets.contains(&address_str) {
+                    // Trouvé !
+                    results.handle_hit(FoundKey::from_match(
+                        &secp, &secret_key, &key_val.into(), &address_str,
+                        Backend::Gpu, device_id, config.mode.as_str(), (&core_start, &core_end),
+                    ));
+                    
+                    if config.stop_on_find {
+                        return;
+                    }
+                }
+            }
+        }
+        
+        stats.add_gpu_keys(keys_batch.len() as u64);
+        worker.add_keys(keys_batch.len() as u64);
+    }
+    
+    println!("Arrêt du worker GPU {}", device_id);
+}
+
+pub fn cpu_worker_thread(core_id: usize, ctx: WorkerContext, core_start: UBig, core_end: UBig) {
+    let WorkerContext { config, targets, stats, found, results, control } = ctx;
+    let secp = Secp256k1::new();
+    let mut since_checkpoint = 0u64;
+    let mut since_position = 0u64;
+    let mut checkpoint_requests = control.checkpoint_requests.load(Ordering::Relaxed);
+    let worker = stats.register_worker(Backend::Cpu, core_id, &core_start, &core_end);
+    let _finished = worker.finish_guard(&control, &found);
+    
+    // Charger le point de contrôle ou commencer du début
+    let mut strategy = match build_strategy(&config, core_id, core_start.clone(), core_end.clone()) {
+        Ok(strategy) => strategy,
+        Err(e) => {
+            eprintln!("⚠️  [CPU {}] {}", core_id, e);
+            return;
+        }
+    };
+    load_checkpoint(&config.checkpoint_dir, &worker, strategy.as_mut());
+
+    // Multiples du pas (1 sauf pour les progressions arithmétiques) pour la dérivation par lots
+    let batch_size = config.batch_size.max(1);
+    let mut deriver = KeyDeriver::new(&secp, &strategy.step().unwrap_or(U256::ONE), batch_size);
+    let key_bounds = (U256::saturating_from(&core_start), U256::saturating_from(&core_end));
+
+    println!("⚙️  [CPU {}] Worker démarré ({}). Plage: {} -> {}", core_id, config.mode, strategy.position(), core_end);
+    worker.set_position(&strategy.position());
+
+    let mut batch = Vec::new();
+    let mut hashes = Vec::new();
+    loop {
+        if found.load(Ordering::Relaxed) && config.stop_on_find {
+            break;
+        }
+
+        // Commandes reçues par l'API de pilotage
+        let requested = control.checkpoint_requests.load(Ordering::Relaxed);
+        if requested != checkpoint_requests {
+            checkpoint_requests = requested;
+            save_checkpoint(&config.checkpoint_dir, strategy.as_ref(), &worker);
+            worker.set_position(&strategy.position());
+        }
+        if control.stop.load(Ordering::Relaxed) {
+            save_checkpoint(&config.checkpoint_dir, strategy.as_ref(), &worker);
+            break;
+        }
+        if control.paused.load(Ordering::Relaxed) {
+            worker.set_position(&strategy.position());
+            thread::sleep(Duration::from_millis(100));
+            continue;
+        }
+
+        // Prochain lot d'environ `batch_size` clés candidates selon le mode de recherche
+        // (un nombre borné d'appels : un lot peut rester vide en mode smart)
+        batch.clear();
+        let mut exhausted = false;
+        for _ in 0..batch_size.div_ceil(CHUNK_SIZE) {
+            if batch.len() >= batch_size {
+                break;
+            }
+            if !strategy.next_batch(&mut batch) {
+                exhausted = true;
+                break;
+            }
+        }
+        if exhausted && batch.is_empty() {
+            break;
+        }
+
+        // Seules les clés effectivement testées sont comptées : ni les clés hors plage, ni celles qui suivent une découverte
+        batch.retain(|key_val| *key_val >= key_bounds.0 && *key_val <= key_bounds.1);
+        deriver.derive(&secp, &batch, &mut hashes);
+        let mut keys_count = batch.len() as u64;
+        let mut stop = false;
+        for (index, address_str) in targets.probe(&hashes) {
+            if stop && index as u64 >= keys_count {
+                break;
+            }
+            let key_val = batch[index];
+            let Some(secret_key) = secret_key_from_u256(&key_val) else { continue };
+            results.handle_hit(FoundKey::from_match(
+                &secp, &secret_key, &key_val.into(), &address_str,
+                Backend::Cpu, core_id, config.mode.as_str(), (&core_start, &core_end),
+            ));
+            if config.stop_on_find {
+                stop = true;
+                keys_count = index as u64 + 1;
+            }
+        }
+
+        stats.add_cpu_keys(keys_count);
+        worker.add_keys(keys_count);
+        since_checkpoint += keys_count;
+        since_position += keys_count;
+
+        if since_checkpoint >= config.checkpoint_interval {
+            save_checkpoint(&config.checkpoint_dir, strategy.as_ref(), &worker);
+            since_checkpoint = 0;
+        }
+        if since_position >= 4096 {
+            worker.set_position(&strategy.position());
+            since_position = 0;
+        }
+        if stop || exhausted { break; }
+    }
+    worker.set_position(&strategy.position());
+    println!("Arrêt du worker CPU {}", core_id);
+}
+
+// Worker des cibles de type clé publique (modes bsgs et kangaroo) : les clés publiques sont résolues l'une
+// après l'autre sur toute la plage [start, end], chaque recherche utilisant `threads` threads
+pub fn pubkey_worker_thread(ctx: WorkerContext, start: UBig, end: UBig, threads: usize) {
+    let WorkerContext { config, targets, stats, found, results, control } = ctx;
+    let secp = Secp256k1::new();
+    let worker = stats.register_worker(Backend::Cpu, 0, &start, &end);
+    let _finished = worker.finish_guard(&control, &found);
+    let (key_start, key_end) = (U256::saturating_from(&start), U256::saturating_from(&end));
+
+    let should_stop = || control.stop.load(Ordering::Relaxed) || (found.load(Ordering::Relaxed) && config.stop_on_find);
+    let on_progress = |count: u64| {
+        stats.add_cpu_keys(count);
+        worker.add_keys(count);
+        while control.paused.load(Ordering::Relaxed) && !should_stop() {
+            thread::sleep(Duration::from_millis(100));
+        }
+    };
+    let search = DlpSearch {
+        threads,
+        symmetric: config.negation_symmetry,
+        seed: config.seed.unwrap_or_else(|| rand::thread_rng().gen()),
+        should_stop: &should_stop,
+        on_progress: &on_progress,
+    };
+
+    println!("⚙️  [CPU] Recherche par clé publique ({}, {} threads). Plage: {} -> {}", config.mode, threads, start, end);
+    for target in targets.public_keys() {
+        if should_stop() {
+            break;
+        }
+        worker.set_position(&start);
+        let outcome = match config.mode {
+            SearchMode::Bsgs => bsgs(&secp, &target.key.inner, &key_start, &key_end, config.bsgs_table_size, &search),
+            _ => Ok(kangaroo(&secp, &target.key.inner, &key_start, &key_end, &search)),
+        };
+        match outcome {
+            Ok(DlpOutcome::Found(key_val)) => {
+                let Some(secret_key) = secret_key_from_u256(&key_val) else { continue };
+                results.handle_hit(FoundKey::from_match(
+                    &secp, &secret_key, &key_val.into(), &target.text,
+                    Backend::Cpu, 0, config.mode.as_str(), (&start, &end),
+                ));
+            },
+            Ok(DlpOutcome::Exhausted) if config.mode == SearchMode::Bsgs => {
+                println!("\n🔍 [CPU] Clé publique {} absente de la plage", target.text);
+            },
+            Ok(DlpOutcome::Exhausted) => {
+                println!("\n🔍 [CPU] Clé publique {} non trouvée : clé probablement hors de la plage", target.text);
+            },
+            Ok(DlpOutcome::Stopped) => break,
+            Err(e) => {
+                eprintln!("⚠️  [CPU] {}", e);
+                break;
+            },
+        }
+    }
+    worker.set_position(&end);
+    println!("Arrêt du worker de recherche par clé publique");
+}
@@ -0,0 +1,401 @@
+// Suite de régression : recherches complètes contre les puzzles déjà résolus de petite taille
+// (clés et adresses publiques). Chaque mode et chaque backend doit retrouver la bonne clé,
+// l'écrire dans le fichier de résultats et la notifier.
+use bitcoin_puzzle_solver::notify::{DispatcherSettings, Notification, NotificationDispatcher, Notifier, NotifyError};
+use bitcoin_puzzle_solver::results::{Backend, FoundKey, ResultHandler, ResultWriter};
+use bitcoin_puzzle_solver::strategy::SearchMode;
+use bitcoin_puzzle_solver::worker::{gpu_worker_thread, WorkerContext};
+use bitcoin_puzzle_solver::{run_search, secret_key_from_u256, ComputeMode, Config, Control, Statistics, TargetSet, U256};
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin::PublicKey;
+use ibig::UBig;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// (numéro du puzzle, clé privée, adresse P2PKH compressée)
+const SOLVED_PUZZLES: [(u32, u64, &str); 20] = [
+    (1, 0x1, "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"),
+    (2, 0x3, "1CUNEBjYrCn2y1SdiUMohaKUi4wpP326Lb"),
+    (3, 0x7, "19ZewH8Kk1PDbSNdJ97FP4EiCjTRaZMZQA"),
+    (4, 0x8, "1EhqbyUMvvs7BfL8goY6qcPbD6YKfPqb7e"),
+    (5, 0x15, "1E6NuFjCi27W5zoXg8TRdcSRq84zJeBW3k"),
+    (6, 0x31, "1PitScNLyp2HCygzadCh7FveTnfmpPbfp8"),
+    (7, 0x4c, "1McVt1vMtCC7yn5b9wgX1833yCcLXzueeC"),
+    (8, 0xe0, "1M92tSqNmQLYw33fuBvjmeadirh1ysMBxK"),
+    (9, 0x1d3, "1CQFwcjw1dwhtkVWBttNLDtqL7ivBonGPV"),
+    (10, 0x202, "1LeBZP5QCwwgXRtmVUvTVrraqPUokyLHqe"),
+    (11, 0x483, "1PgQVLmst3Z314JrQn5TNiys8Hc38TcXJu"),
+    (12, 0xa7b, "1DBaumZxUkM4qMQRt2LVWyFJq5kDtSZQot"),
+    (13, 0x1460, "1Pie8JkxBT6MGPz9Nvi3fsPkr2D8q3GBc1"),
+    (14, 0x2930, "1ErZWg5cFCe4Vw5BzgfzB74VNLaXEiEkhk"),
+    (15, 0x68f3, "1QCbW9HWnwQWiQqVo5exhAnmfqKRrCRsvW"),
+    (16, 0xc936, "1BDyrQ6WoF8VN3g9SAS1iKZcPzFfnDVieY"),
+    (17, 0x1764f, "1HduPEXZRdG26SUT5Yk83mLkPyjnZuJ7Bm"),
+    (18, 0x3080d, "1GnNTmTVLZiqQfLbAdp9DVdicEnB5GoERE"),
+    (19, 0x5749f, "1NWmZRpHH4XSPwsW6dsS3nrNWfL1yrJj4w"),
+    (20, 0xd2c55, "1HsMJxNiV7TLxmoF6uJNkydxPFDog4NQum"),
+];
+
+// Fenêtre de 4001 clés contenant la clé du puzzle (clé - 3000 est un multiple de 3 du début)
+fn window(key: u64) -> (u64, u64) {
+    (key - 3000, key + 1000)
+}
+
+// Notificateur de test : conserve les notifications reçues
+struct RecordingNotifier(Arc<Mutex<Vec<Notification>>>);
+
+impl Notifier for RecordingNotifier {
+    fn name(&self) -> &str {
+        "recording"
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        self.0.lock().unwrap().push(notification.clone());
+        Ok(())
+    }
+}
+
+// Recherche isolée dans un répertoire temporaire (résultats, outbox et points de contrôle)
+struct SearchRun {
+    dir: PathBuf,
+    config: Config,
+    notifications: Arc<Mutex<Vec<Notification>>>,
+}
+
+impl SearchRun {
+    fn new(name: &str, mode: SearchMode, start: u64, end: u64) -> Self {
+        let dir = std::env::temp_dir().join(format!("solver_regression_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = |file: &str| dir.join(file).to_str().unwrap().to_string();
+        let config = Config {
+            start: format!("0x{:x}", start),
+            end: format!("0x{:x}", end),
+            cores: 1,
+            mode,
+            compute_mode: ComputeMode::CPU,
+            switch_interval: 64,
+            stop_on_find: true,
+            checkpoint_interval: u64::MAX,
+            checkpoint_dir: dir.to_str().unwrap().to_string(),
+            results_file: path("found.jsonl"),
+            summary_file: None,
+            notify_outbox: path("outbox.jsonl"),
+            progress_notify_interval: 0,
+            heartbeat_timeout: 0,
+            worker_stall_timeout: 0,
+            ..Config::default()
+        };
+
+        Self { dir, config, notifications: Arc::new(Mutex::new(Vec::new())) }
+    }
+
+    fn notifiers(&self) -> Vec<Box<dyn Notifier>> {
+        vec![Box::new(RecordingNotifier(self.notifications.clone()))]
+    }
+
+    fn run(&self, targets: &[(u32, u64, &str)]) {
+        let targets = TargetSet::from_addresses(targets.iter().map(|(_, _, address)| *address));
+        let outcome = run_search(Arc::new(self.config.clone()), Arc::new(targets), self.notifiers()).unwrap();
+        assert!(outcome.keys_checked > 0);
+    }
+
+    fn found_keys(&self) -> Vec<FoundKey> {
+        fs::read_to_string(&self.config.results_file)
+            .unwrap_or_default()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    fn notified_keys(&self) -> Vec<FoundKey> {
+        self.notifications
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|notification| match notification {
+                Notification::KeyFound { record } => Some(record.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // Chaque puzzle attendu est écrit une fois dans les résultats et notifié avec la bonne clé
+    fn assert_found(&self, puzzles: &[(u32, u64, &str)]) {
+        let found = self.found_keys();
+        let notified = self.notified_keys();
+        for (number, key, address) in puzzles {
+            let expected_hex = format!("{:x}", key);
+            let records: Vec<_> = found.iter().filter(|record| record.target == *address).collect();
+            assert_eq!(records.len(), 1, "puzzle {} : {} enregistrement(s)", number, records.len());
+            assert_eq!(records[0].private_key_hex, expected_hex, "puzzle {}", number);
+            assert_eq!(records[0].address_type, "p2pkh_compressed");
+            assert_eq!(records[0].mode, self.config.mode.as_str());
+            assert!(
+                notified.iter().any(|record| record.target == *address && record.private_key_hex == expected_hex),
+                "puzzle {} non notifié",
+                number
+            );
+        }
+    }
+}
+
+impl Drop for SearchRun {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+// Worker GPU seul sur [start, end], sans passer par run_search (aucun GPU détecté dans les tests)
+fn run_gpu_worker(run: &SearchRun, address: &str, start: u64, end: u64) {
+    let stats = Arc::new(Statistics::new());
+    let found = Arc::new(AtomicBool::new(false));
+    let settings = DispatcherSettings {
+        max_retries: 0,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(10),
+        outbox_file: run.config.notify_outbox.clone(),
+        shutdown_grace: Duration::from_secs(5),
+    };
+    let notifications = Arc::new(NotificationDispatcher::start(run.notifiers(), settings));
+    let writer = ResultWriter::new(&run.config.results_file, None, None);
+    let ctx = WorkerContext {
+        config: Arc::new(run.config.clone()),
+        targets: Arc::new(TargetSet::from_addresses([address])),
+        stats: stats.clone(),
+        found: found.clone(),
+        results: Arc::new(ResultHandler::new(writer, notifications.clone(), stats.clone(), found)),
+        control: Arc::new(Control::default()),
+    };
+
+    gpu_worker_thread(0, ctx, UBig::from(start), UBig::from(end));
+    notifications.shutdown();
+}
+
+// Chaque puzzle 17 à 20 dans une fenêtre de 4001 clés, avec le mode donné
+fn check_mode(mode: SearchMode, configure: impl Fn(&mut Config)) {
+    for puzzle in &SOLVED_PUZZLES[16..] {
+        let (number, key, _) = *puzzle;
+        let (start, end) = window(key);
+        let mut run = SearchRun::new(&format!("{}_{}", mode, number), mode, start, end);
+        configure(&mut run.config);
+        run.run(&[*puzzle]);
+        run.assert_found(&[*puzzle]);
+    }
+}
+
+#[test]
+fn sequential_scan_finds_puzzles_1_to_16() {
+    let puzzles = &SOLVED_PUZZLES[..16];
+    let mut run = SearchRun::new("sequential_full", SearchMode::Sequential, 1, 0xffff);
+    run.config.cores = 2;
+    run.config.stop_on_find = false;
+    run.run(puzzles);
+
+    run.assert_found(puzzles);
+    assert_eq!(run.found_keys().len(), 16);
+}
+
+#[test]
+fn sequential_mode_finds_puzzles_17_to_20() {
+    check_mode(SearchMode::Sequential, |config| config.cores = 2);
+}
+
+#[test]
+fn random_mode_finds_puzzles_17_to_20() {
+    check_mode(SearchMode::Random, |_| {});
+}
+
+#[test]
+fn cpu_batch_sizes_find_puzzles_17_to_20() {
+    // Lots d'une clé (aucune addition), et lots qui ne sont pas un multiple des lots des stratégies
+    check_mode(SearchMode::Sequential, |config| config.batch_size = 1);
+    check_mode(SearchMode::Stride, |config| {
+        config.batch_size = 300;
+        config.stride = UBig::from(3u8);
+    });
+}
+
+#[test]
+fn random_subintervals_cover_the_range_once() {
+    // Sans arrêt à la découverte, chaque coeur parcourt sa plage par sous-intervalles puis s'arrête
+    let puzzle = SOLVED_PUZZLES[18];
+    let (start, end) = window(puzzle.1);
+    let mut run = SearchRun::new("random_subintervals", SearchMode::Random, start, end);
+    run.config.cores = 2;
+    run.config.subinterval_ratio = 0.01;
+    run.config.stop_on_find = false;
+
+    let targets = TargetSet::from_addresses([puzzle.2]);
+    let outcome = run_search(Arc::new(run.config.clone()), Arc::new(targets), run.notifiers()).unwrap();
+    assert_eq!(outcome.keys_checked, end - start + 1);
+    run.assert_found(&[puzzle]);
+}
+
+#[test]
+fn smart_mode_finds_puzzles_17_to_20() {
+    check_mode(SearchMode::Smart, |_| {});
+}
+
+#[test]
+fn stride_mode_finds_puzzles_17_to_20() {
+    check_mode(SearchMode::Stride, |config| config.stride = UBig::from(3u8));
+}
+
+#[test]
+fn stride_residue_classes_split_work_between_machines() {
+    // Trois "machines" (stride 3, décalages 0 à 2) sur deux coeurs chacune : seule celle dont la classe
+    // de résidus contient la clé la trouve, et chaque clé de la fenêtre est testée exactement une fois
+    let puzzle = SOLVED_PUZZLES[18];
+    let (start, end) = window(puzzle.1);
+    let mut total = 0;
+    for offset in 0..3u64 {
+        let mut run = SearchRun::new(&format!("stride_offset_{}", offset), SearchMode::Stride, start, end);
+        run.config.cores = 2;
+        run.config.stop_on_find = false;
+        run.config.stride = UBig::from(3u8);
+        run.config.stride_offset = UBig::from(offset);
+        let targets = TargetSet::from_addresses([puzzle.2]);
+        let outcome = run_search(Arc::new(run.config.clone()), Arc::new(targets), run.notifiers()).unwrap();
+        total += outcome.keys_checked;
+        if (puzzle.1 - start) % 3 == offset {
+            run.assert_found(&[puzzle]);
+        } else {
+            assert!(run.found_keys().is_empty());
+        }
+    }
+    assert_eq!(total, end - start + 1);
+}
+
+#[test]
+fn kangaroo_mode_finds_puzzles_17_to_20() {
+    check_mode(SearchMode::Kangaroo, |_| {});
+}
+
+// Cibles de type clé publique : chaque puzzle 17 à 20 sur toute sa plage [2^(n-1), 2^n - 1]
+fn check_public_key_mode(mode: SearchMode, negation_symmetry: bool) {
+    let secp = Secp256k1::new();
+    for (number, key, address) in &SOLVED_PUZZLES[16..] {
+        let secret_key = secret_key_from_u256(&U256::from(*key)).unwrap();
+        let public_key = PublicKey::new(secret_key.public_key(&secp)).to_string();
+        let name = format!("{}_{}_{}", mode, negation_symmetry, number);
+        let mut run = SearchRun::new(&name, mode, 1 << (number - 1), (1 << number) - 1);
+        run.config.cores = 2;
+        run.config.negation_symmetry = negation_symmetry;
+        run.config.bsgs_table_size = 256;
+        let outcome = run_search(
+            Arc::new(run.config.clone()),
+            Arc::new(TargetSet::from_targets([public_key.clone()])),
+            run.notifiers(),
+        )
+        .unwrap();
+        assert_eq!(outcome.found, 1, "puzzle {} ({}, symétrie {})", number, mode, negation_symmetry);
+
+        let found = run.found_keys();
+        assert_eq!(found.len(), 1, "puzzle {}", number);
+        assert_eq!(found[0].target, public_key);
+        assert_eq!(found[0].public_key, public_key);
+        assert_eq!(found[0].private_key_hex, format!("{:x}", key), "puzzle {}", number);
+        assert_eq!(found[0].address_type, "p2pkh_compressed");
+        assert!(TargetSet::from_targets([public_key]).contains(address));
+    }
+}
+
+#[test]
+fn bsgs_mode_finds_public_keys_of_puzzles_17_to_20() {
+    check_public_key_mode(SearchMode::Bsgs, false);
+    check_public_key_mode(SearchMode::Bsgs, true);
+}
+
+#[test]
+fn kangaroo_mode_finds_public_keys_of_puzzles_17_to_20() {
+    check_public_key_mode(SearchMode::Kangaroo, false);
+    check_public_key_mode(SearchMode::Kangaroo, true);
+}
+
+#[test]
+fn bsgs_mode_requires_public_key_targets() {
+    let puzzle = SOLVED_PUZZLES[17];
+    let (start, end) = window(puzzle.1);
+    let run = SearchRun::new("bsgs_addresses", SearchMode::Bsgs, start, end);
+    let targets = TargetSet::from_addresses([puzzle.2]);
+    assert!(run_search(Arc::new(run.config.clone()), Arc::new(targets), run.notifiers()).is_err());
+}
+
+#[test]
+fn shuffle_mode_finds_puzzles_17_to_20() {
+    check_mode(SearchMode::Shuffle, |_| {});
+}
+
+#[cfg(feature = "scripting")]
+#[test]
+fn smart_script_finds_puzzle_18() {
+    let puzzle = SOLVED_PUZZLES[17];
+    let (start, end) = window(puzzle.1);
+    let mut run = SearchRun::new("smart_script", SearchMode::Smart, start, end);
+    let script = run.dir.join("candidates.rhai");
+    fs::write(&script, "fn candidates(base, start, end) { [range(base, base + 63)] }").unwrap();
+    run.config.smart_patterns = Vec::new();
+    run.config.smart_script = Some(script.to_str().unwrap().to_string());
+    run.run(&[puzzle]);
+    run.assert_found(&[puzzle]);
+}
+
+#[test]
+fn mask_mode_finds_puzzles_17_to_20() {
+    // Les deux derniers chiffres hexadécimaux inconnus, ainsi qu'un chiffre au milieu de la clé
+    for puzzle in &SOLVED_PUZZLES[16..] {
+        let (number, key, _) = *puzzle;
+        let hex_key = format!("{:x}", key);
+        let len = hex_key.len();
+        let pattern = format!("0x{}?{}??", &hex_key[..len - 4], &hex_key[len - 3..len - 2]);
+        let (start, end) = window(key);
+        let mut run = SearchRun::new(&format!("mask_{}", number), SearchMode::Mask, start, end);
+        run.config.cores = 2;
+        run.config.mask = Some(pattern.parse().unwrap());
+        run.run(&[*puzzle]);
+        run.assert_found(&[*puzzle]);
+    }
+}
+
+#[test]
+fn hybrid_compute_mode_finds_puzzle_18() {
+    let puzzle = SOLVED_PUZZLES[17];
+    let (start, end) = window(puzzle.1);
+    let mut run = SearchRun::new("hybrid", SearchMode::Random, start, end);
+    run.config.compute_mode = ComputeMode::Hybrid;
+    run.config.gpu_batch_size = 512;
+    run.run(&[puzzle]);
+    run.assert_found(&[puzzle]);
+}
+
+#[test]
+fn gpu_backend_finds_puzzles_17_to_20() {
+    for puzzle in &SOLVED_PUZZLES[16..] {
+        let (number, key, address) = *puzzle;
+        let (start, end) = window(key);
+        let mut run = SearchRun::new(&format!("gpu_{}", number), SearchMode::Random, start, end);
+        run.config.gpu_batch_size = 512;
+
+        run_gpu_worker(&run, address, start, end);
+        run.assert_found(&[*puzzle]);
+        assert_eq!(run.found_keys()[0].backend, Backend::Gpu);
+    }
+}
+
+#[test]
+fn gpu_backend_samples_mask_keys() {
+    let (number, key, address) = SOLVED_PUZZLES[19];
+    let (start, end) = window(key);
+    let mut run = SearchRun::new(&format!("gpu_mask_{}", number), SearchMode::Mask, start, end);
+    run.config.gpu_batch_size = 64;
+    run.config.mask = Some(format!("0x{}??", &format!("{:x}", key)[..3]).parse().unwrap());
+
+    run_gpu_worker(&run, address, start, end);
+    run.assert_found(&[SOLVED_PUZZLES[19]]);
+    assert_eq!(run.found_keys()[0].backend, Backend::Gpu);
+}